@@ -1,9 +1,57 @@
 use std::process;
 
-use clap::{App, Arg, SubCommand};
-use zokrates_credential_core::{issuer, prover, verifier};
+use chrono::NaiveDate;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use zokrates_credential_core::{
+    backend::{ArkBackend, ZkBackend, ZokratesCli, UNIVERSAL_SETUP_SIZE},
+    ceremony,
+    challenge::ChallengeStore,
+    circuit::{Circuit, CircuitSpec, MultiCircuitSpec},
+    config::Config,
+    credential::Credential,
+    did,
+    eddsa::{self, ISSUER_PUBLIC_KEY},
+    error::CredentialError,
+    holder, issuer,
+    issuer::CredentialRequest,
+    issuer_set::TrustedIssuers,
+    nullifier::NullifierStore,
+    profile::IssuerProfile,
+    proof::{MultiPresentation, Presentation, Proof},
+    prover::{self, CredentialInput, ProofOptions},
+    proving_system::{self, ProvingSystem},
+    revocation::RevocationList,
+    status_list::StatusList,
+    verifier,
+    verifier::VerifierContext,
+};
 
 use std::env;
+use std::path::Path;
+
+// 코어 오류 종류별 종료 코드 (1은 검증 결과가 거짓일 때 사용)
+fn exit_code(error: &CredentialError) -> i32 {
+    match error {
+        CredentialError::Io { .. } => 2,
+        CredentialError::Zokrates { .. } => 3,
+        CredentialError::Parse { .. } => 4,
+        CredentialError::InvalidSignature(_) => 5,
+        CredentialError::UnsatisfiedPredicate(_) => 6,
+        CredentialError::MissingSetup(_) => 7,
+        CredentialError::InvalidInput(_) => 8,
+    }
+}
+
+// 오류를 출력하고 오류 종류에 맞는 종료 코드로 종료
+fn fail<T>(error: CredentialError) -> T {
+    eprintln!("Error: {}", error);
+    process::exit(exit_code(&error));
+}
+
+// 잘못된 인자 값을 InvalidInput 종료 코드로 알리고 종료
+fn invalid_input<T>(message: &str) -> T {
+    fail(CredentialError::InvalidInput(message.to_owned()))
+}
 
 fn to_absolute_path(relative_path: &str) -> String {
     let current_dir = env::current_dir().expect("Failed to get current directory");
@@ -11,13 +59,243 @@ fn to_absolute_path(relative_path: &str) -> String {
 
     match absolute_path.canonicalize() {
         Ok(canonical_path) => canonical_path.to_str().unwrap().to_string(),
-        Err(_) => {
-            absolute_path.to_str().unwrap().to_string()
-        },
+        Err(_) => absolute_path.to_str().unwrap().to_string(),
+    }
+}
+
+fn profile_arg<'a>() -> Arg<'a> {
+    Arg::with_name("profile")
+        .help("Path to the issuer profile JSON (defaults to the built-in profile)")
+        .long("profile")
+        .takes_value(true)
+}
+
+fn issuer_profile(matches: &ArgMatches) -> IssuerProfile {
+    matches
+        .value_of("profile")
+        .map(|path| IssuerProfile::load(&to_absolute_path(path)).unwrap_or_else(fail))
+        .unwrap_or_default()
+}
+
+fn reveal_arg<'a>() -> Arg<'a> {
+    Arg::with_name("reveal")
+        .help("Attributes to disclose as public inputs (e.g. department,id)")
+        .long("reveal")
+        .takes_value(true)
+        .multiple_occurrences(true)
+        .use_value_delimiter(true)
+}
+
+fn use_birth_date_arg<'a>() -> Arg<'a> {
+    Arg::with_name("use_birth_date")
+        .help("Check the minimum age against the birth date instead of the stored age")
+        .long("use-birth-date")
+}
+
+fn check_revocation_arg<'a>() -> Arg<'a> {
+    Arg::with_name("check_revocation")
+        .help("Prove that the credential is not in the issuer's revocation registry")
+        .long("check-revocation")
+}
+
+fn revocation_list_arg<'a>() -> Arg<'a> {
+    Arg::with_name("revocation_list")
+        .help("Path to the revocation_list.json published by the issuer")
+        .long("revocation-list")
+        .takes_value(true)
+}
+
+fn issuer_set_arg<'a>() -> Arg<'a> {
+    Arg::with_name("issuer_set")
+        .help("Prove that the issuer is in a trusted issuer set without revealing which one")
+        .long("issuer-set")
+}
+
+fn trusted_issuers_arg<'a>() -> Arg<'a> {
+    Arg::with_name("trusted_issuers")
+        .help("Path to the trusted_issuers.json built by the verifier")
+        .long("trusted-issuers")
+        .takes_value(true)
+}
+
+fn holder_binding_arg<'a>() -> Arg<'a> {
+    Arg::with_name("holder_binding")
+        .help("Prove knowledge of the holder secret committed in the credential")
+        .long("holder-binding")
+}
+
+fn challenge_arg<'a>() -> Arg<'a> {
+    Arg::with_name("challenge")
+        .help("Bind the proof to a verifier challenge (requires --holder-binding)")
+        .long("challenge")
+        .requires("holder_binding")
+}
+
+fn nullifier_arg<'a>() -> Arg<'a> {
+    Arg::with_name("nullifier")
+        .help("Output a per-scope nullifier to detect repeated use (requires --holder-binding)")
+        .long("nullifier")
+        .requires("holder_binding")
+}
+
+fn scope_arg<'a>() -> Arg<'a> {
+    Arg::with_name("scope")
+        .help("Scope of the nullifier (e.g. election-2026)")
+        .long("scope")
+        .takes_value(true)
+}
+
+fn pseudonym_arg<'a>() -> Arg<'a> {
+    Arg::with_name("pseudonym")
+        .help("Output a per-verifier pseudonym (requires --holder-binding)")
+        .long("pseudonym")
+        .requires("holder_binding")
+}
+
+fn verifier_domain_arg<'a>() -> Arg<'a> {
+    Arg::with_name("verifier_domain")
+        .help("Verifier domain the pseudonym is derived for (e.g. shop.example)")
+        .long("verifier-domain")
+        .takes_value(true)
+}
+
+fn native_arg<'a>() -> Arg<'a> {
+    Arg::with_name("native")
+        .help("Uses the built-in arkworks circuit instead of the zokrates executable (reveal and birth date options only)")
+        .long("native")
+}
+
+fn proving_scheme_arg<'a>() -> Arg<'a> {
+    Arg::with_name("proving_scheme")
+        .help("Proving scheme for the ZoKrates setup and proofs")
+        .long("proving-scheme")
+        .possible_values(proving_system::SCHEMES)
+        .default_value("g16")
+}
+
+fn backend_arg<'a>() -> Arg<'a> {
+    Arg::with_name("backend")
+        .help("ZoKrates backend for the setup and proofs")
+        .long("backend")
+        .possible_values(proving_system::BACKENDS)
+        .default_value("ark")
+}
+
+fn curve_arg<'a>() -> Arg<'a> {
+    Arg::with_name("curve")
+        .help("Curve the circuit is compiled for")
+        .long("curve")
+        .possible_values(proving_system::CURVES)
+        .default_value("bn128")
+}
+
+// 범용 설정 방식이면 저장된 SRS로 아직 없는 회로의 키를 유도
+fn derive_keys(matches: &ArgMatches, spec: &impl Circuit) {
+    let system = proving_system(matches);
+    if system.is_universal()
+        && verifier::derive_keys(&ZokratesCli::new(system), spec).unwrap_or_else(fail)
+    {
+        println!("Derived keys from the universal setup");
+    }
+}
+
+fn proving_system(matches: &ArgMatches) -> ProvingSystem {
+    ProvingSystem::new(
+        matches.value_of("proving_scheme").unwrap(),
+        matches.value_of("backend").unwrap(),
+        matches.value_of("curve").unwrap(),
+    )
+    .unwrap_or_else(fail)
+}
+
+// --native가 지정되면 zokrates 없이 arkworks로 설정과 증명을 수행
+fn zk_backend(matches: &ArgMatches) -> Box<dyn ZkBackend> {
+    let system = proving_system(matches);
+    if matches.is_present("native") {
+        if system != ProvingSystem::default() {
+            fail(CredentialError::InvalidInput(
+                "--native supports only g16 on bn128 with the ark backend".to_owned(),
+            ))
+        }
+        Box::new(ArkBackend::new(&circuit_spec(matches)).unwrap_or_else(fail))
+    } else {
+        Box::new(ZokratesCli::new(system))
+    }
+}
+
+fn circuit_spec(matches: &ArgMatches) -> CircuitSpec {
+    let reveal = matches
+        .values_of("reveal")
+        .map(|values| values.map(str::to_owned).collect::<Vec<_>>())
+        .unwrap_or_default();
+    CircuitSpec {
+        revocation: matches.is_present("check_revocation"),
+        issuer_set: matches.is_present("issuer_set"),
+        holder_binding: matches.is_present("holder_binding"),
+        challenge: matches.is_present("challenge"),
+        nullifier: matches.is_present("nullifier"),
+        pseudonym: matches.is_present("pseudonym"),
+        ..CircuitSpec::new(&reveal, matches.is_present("use_birth_date")).unwrap_or_else(fail)
+    }
+}
+
+fn credentials_arg<'a>() -> Arg<'a> {
+    Arg::with_name("credentials")
+        .help("Number of credentials combined in one proof")
+        .long("credentials")
+        .takes_value(true)
+        .required(true)
+}
+
+fn reveal_of_arg<'a>() -> Arg<'a> {
+    Arg::with_name("reveal_of")
+        .help("Attributes to disclose per credential as <index>:<attribute> (e.g. 0:type,1:type)")
+        .long("reveal-of")
+        .takes_value(true)
+        .multiple_occurrences(true)
+        .use_value_delimiter(true)
+}
+
+fn equal_arg<'a>() -> Arg<'a> {
+    Arg::with_name("equal")
+        .help("Attributes that must have the same value in every credential (e.g. id)")
+        .long("equal")
+        .takes_value(true)
+        .multiple_occurrences(true)
+        .use_value_delimiter(true)
+}
+
+fn credential_count(matches: &ArgMatches) -> usize {
+    matches
+        .value_of("credentials")
+        .unwrap()
+        .parse()
+        .unwrap_or_else(|_| invalid_input("Number of credentials must be a number"))
+}
+
+fn multi_spec(matches: &ArgMatches, count: usize) -> MultiCircuitSpec {
+    let mut reveal = vec![Vec::new(); count];
+    for value in matches.values_of("reveal_of").into_iter().flatten() {
+        let (index, attribute) = value
+            .split_once(':')
+            .unwrap_or_else(|| invalid_input("Expected <index>:<attribute> for --reveal-of"));
+        let index = index
+            .parse::<usize>()
+            .ok()
+            .filter(|&index| index < count)
+            .unwrap_or_else(|| invalid_input("Credential index out of range in --reveal-of"));
+        reveal[index].push(attribute.to_owned());
     }
+    let equal = matches
+        .values_of("equal")
+        .map(|values| values.map(str::to_owned).collect::<Vec<_>>())
+        .unwrap_or_default();
+    MultiCircuitSpec::new(&reveal, &equal, matches.is_present("holder_binding"))
+        .unwrap_or_else(fail)
 }
 
 fn main() {
+    let universal_setup_size = UNIVERSAL_SETUP_SIZE.to_string();
     let matches = App::new("ZoKrates Credential Issuer")
         .version("1.0")
         .author("ham3798 <5023798@naver.com>")
@@ -34,6 +312,13 @@ fn main() {
                                 .required(true)
                                 .index(1),
                         )
+                        .arg(
+                            Arg::with_name("subject_did")
+                                .help("did:key of the credential subject (printed by prover create_holder_secret)")
+                                .long("subject-did")
+                                .takes_value(true)
+                                .required(true),
+                        )
                         .arg(
                             Arg::with_name("name")
                                 .help("Name of the credential owner")
@@ -63,11 +348,68 @@ fn main() {
                                 .help("Path to save the signature")
                                 .required(true)
                                 .index(6),
-                        ),
+                        )
+                        .arg(
+                            Arg::with_name("birth_date")
+                                .help("Date of birth of the credential owner (YYYY-MM-DD)")
+                                .long("birth-date")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("holder_commitment")
+                                .help(
+                                    "Commitment to the holder secret (prover create_holder_secret)",
+                                )
+                                .long("holder-commitment")
+                                .takes_value(true),
+                        )
+                        .arg(profile_arg()),
                 )
                 .subcommand(
                     SubCommand::with_name("setup")
                         .about("Performs setup operations for the issuer"),
+                )
+                .subcommand(
+                    SubCommand::with_name("revoke")
+                        .about("Adds a credential to the revocation registry")
+                        .arg(
+                            Arg::with_name("credential_id")
+                                .help("The ID of the credential to revoke")
+                                .required(true)
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("publish_revocation_root")
+                        .alias("publish-revocation-root")
+                        .about("Signs the revocation registry root and writes revocation_list.json")
+                        .arg(
+                            Arg::with_name("destination_path")
+                                .help("The destination path for revocation_list.json")
+                                .required(true)
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("export_public_key")
+                        .about("Writes the issuer public key for multi-credential proofs")
+                        .arg(
+                            Arg::with_name("destination_path")
+                                .help("The destination path for the issuer_public_key file")
+                                .required(true)
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("publish_status_list")
+                        .about("Signs the bitstring status list and writes status_list.json")
+                        .arg(
+                            Arg::with_name("destination_path")
+                                .help("The destination path for status_list.json")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(profile_arg()),
                 ),
         )
         .subcommand(
@@ -81,9 +423,49 @@ fn main() {
                                 .help("The destination path to copy the proving key")
                                 .required(true)
                                 .index(1),
-                        ),
+                        )
+                        .arg(reveal_arg())
+                        .arg(use_birth_date_arg())
+                        .arg(check_revocation_arg())
+                        .arg(issuer_set_arg())
+                        .arg(holder_binding_arg())
+                        .arg(challenge_arg())
+                        .arg(nullifier_arg())
+                        .arg(pseudonym_arg())
+                        .arg(proving_scheme_arg())
+                        .arg(backend_arg())
+                        .arg(curve_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("universal_setup")
+                        .alias("universal-setup")
+                        .about("Creates the universal SRS (marlin) that every circuit derives its keys from")
+                        .arg(
+                            Arg::with_name("size")
+                                .help("Maximum circuit size as a power of two")
+                                .long("size")
+                                .takes_value(true)
+                                .default_value(&universal_setup_size),
+                        )
+                        .arg(backend_arg())
+                        .arg(curve_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("setup")
+                        .about("Runs the ZoKrates setup")
+                        .arg(reveal_arg())
+                        .arg(use_birth_date_arg())
+                        .arg(check_revocation_arg())
+                        .arg(issuer_set_arg())
+                        .arg(holder_binding_arg())
+                        .arg(challenge_arg())
+                        .arg(nullifier_arg())
+                        .arg(pseudonym_arg())
+                        .arg(native_arg())
+                        .arg(proving_scheme_arg())
+                        .arg(backend_arg())
+                        .arg(curve_arg()),
                 )
-                .subcommand(SubCommand::with_name("setup").about("Runs the ZoKrates setup"))
                 .subcommand(
                     SubCommand::with_name("get_verify_contract")
                         .about("Exports a verifier smart contract")
@@ -92,6 +474,228 @@ fn main() {
                                 .help("The destination path to export the verifier contract")
                                 .required(true)
                                 .index(1),
+                        )
+                        .arg(reveal_arg())
+                        .arg(use_birth_date_arg())
+                        .arg(check_revocation_arg())
+                        .arg(issuer_set_arg())
+                        .arg(holder_binding_arg())
+                        .arg(challenge_arg())
+                        .arg(nullifier_arg())
+                        .arg(pseudonym_arg())
+                        .arg(proving_scheme_arg())
+                        .arg(backend_arg())
+                        .arg(curve_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("check_min_age")
+                        .about("Checks the minimum age recorded in a proof's public inputs")
+                        .arg(
+                            Arg::with_name("proof_path")
+                                .help("Path to the proof.json file")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("min_age")
+                                .help("Minimum age the verifier requires")
+                                .long("min-age")
+                                .takes_value(true)
+                                .default_value("20"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("verify")
+                        .about("Verifies a proof.json (Groth16 on BN254 in-process, other schemes with zokrates verify)")
+                        .arg(
+                            Arg::with_name("proof_path")
+                                .help("Path to the proof.json file")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("verification_key")
+                                .help("Path to verification.key (defaults to the circuit of the given flags)")
+                                .long("verification-key")
+                                .takes_value(true),
+                        )
+                        .arg(reveal_arg())
+                        .arg(use_birth_date_arg())
+                        .arg(check_revocation_arg())
+                        .arg(issuer_set_arg())
+                        .arg(holder_binding_arg())
+                        .arg(challenge_arg())
+                        .arg(nullifier_arg())
+                        .arg(pseudonym_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("check_presentation")
+                        .about("Verifies the proof of a presentation and checks it against the proof's public inputs")
+                        .arg(
+                            Arg::with_name("presentation_path")
+                                .help("Path to the presentation.json file")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("min_age")
+                                .help("Minimum age the verifier requires")
+                                .long("min-age")
+                                .takes_value(true)
+                                .default_value("20"),
+                        )
+                        .arg(
+                            Arg::with_name("time_tolerance")
+                                .help(
+                                    "Allowed difference in seconds between the proof time and now",
+                                )
+                                .long("time-tolerance")
+                                .takes_value(true)
+                                .default_value("600"),
+                        )
+                        .arg(reveal_arg())
+                        .arg(use_birth_date_arg())
+                        .arg(check_revocation_arg().requires("revocation_list"))
+                        .arg(issuer_set_arg().requires("trusted_issuers"))
+                        .arg(trusted_issuers_arg())
+                        .arg(holder_binding_arg())
+                        .arg(challenge_arg())
+                        .arg(nullifier_arg().requires("scope"))
+                        .arg(scope_arg())
+                        .arg(pseudonym_arg().requires("verifier_domain"))
+                        .arg(verifier_domain_arg())
+                        .arg(revocation_list_arg())
+                        .arg(proving_scheme_arg())
+                        .arg(backend_arg())
+                        .arg(curve_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("resolve_did")
+                        .alias("resolve-did")
+                        .about("Resolves a BabyJubJub did:key to its DID document")
+                        .arg(
+                            Arg::with_name("did")
+                                .help("The did:key to resolve")
+                                .required(true)
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("build_trusted_issuers")
+                        .alias("build-trusted-issuers")
+                        .about("Builds the trusted issuer set root from a directory of issuer public keys")
+                        .arg(
+                            Arg::with_name("keys_dir")
+                                .help("Directory of issuer_public_key files")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("destination_path")
+                                .help("The destination path for trusted_issuers.json")
+                                .required(true)
+                                .index(2),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("setup_multi")
+                        .about("Runs the ZoKrates setup for a multi-credential circuit")
+                        .arg(credentials_arg())
+                        .arg(proving_scheme_arg())
+                        .arg(backend_arg())
+                        .arg(curve_arg())
+                        .arg(reveal_of_arg())
+                        .arg(equal_arg())
+                        .arg(holder_binding_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("get_multi_proving_key")
+                        .about("Copies the proving key of a multi-credential circuit")
+                        .arg(
+                            Arg::with_name("destination_path")
+                                .help("The destination path to copy the proving key")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(credentials_arg())
+                        .arg(reveal_of_arg())
+                        .arg(equal_arg())
+                        .arg(holder_binding_arg())
+                        .arg(proving_scheme_arg())
+                        .arg(backend_arg())
+                        .arg(curve_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("check_multi_presentation")
+                        .about("Verifies the proof of a multi-credential presentation and checks it against the proof's public inputs")
+                        .arg(
+                            Arg::with_name("presentation_path")
+                                .help("Path to the presentation.json file")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("trusted_issuer")
+                                .help("Path to a trusted issuer_public_key file (defaults to this issuer)")
+                                .long("trusted-issuer")
+                                .takes_value(true)
+                                .multiple_occurrences(true),
+                        )
+                        .arg(
+                            Arg::with_name("time_tolerance")
+                                .help(
+                                    "Allowed difference in seconds between the proof time and now",
+                                )
+                                .long("time-tolerance")
+                                .takes_value(true)
+                                .default_value("600"),
+                        )
+                        .arg(credentials_arg())
+                        .arg(reveal_of_arg())
+                        .arg(equal_arg())
+                        .arg(holder_binding_arg())
+                        .arg(proving_scheme_arg())
+                        .arg(backend_arg())
+                        .arg(curve_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("create_challenge")
+                        .about("Issues a challenge that the next proof must answer")
+                        .arg(
+                            Arg::with_name("destination_path")
+                                .help("The destination path for challenge.json")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("verifier_id")
+                                .help("Identifier of the verifier (e.g. its URL)")
+                                .long("verifier-id")
+                                .takes_value(true)
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("lifetime")
+                                .help("Seconds until the challenge expires")
+                                .long("lifetime")
+                                .takes_value(true)
+                                .default_value("600"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("check_status")
+                        .about("Checks a credential's status against a published status list")
+                        .arg(
+                            Arg::with_name("credential_path")
+                                .help("Path to the credential.json file")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("status_list_path")
+                                .help("Path to the status_list.json published by the issuer")
+                                .required(true)
+                                .index(2),
                         ),
                 ),
         )
@@ -124,14 +728,245 @@ fn main() {
                                 .help("Destination path for the generated proof")
                                 .required(true)
                                 .index(4),
+                        )
+                        .arg(
+                            Arg::with_name("min_age")
+                                .help("Minimum age the proof attests to (public input)")
+                                .long("min-age")
+                                .takes_value(true)
+                                .default_value("20"),
+                        )
+                        .arg(reveal_arg())
+                        .arg(use_birth_date_arg())
+                        .arg(check_revocation_arg().requires("revocation_list"))
+                        .arg(issuer_set_arg().requires("trusted_issuers"))
+                        .arg(trusted_issuers_arg())
+                        .arg(holder_binding_arg().requires("holder_secret"))
+                        .arg(challenge_arg().requires("challenge_path"))
+                        .arg(nullifier_arg().requires("scope"))
+                        .arg(scope_arg())
+                        .arg(pseudonym_arg().requires("verifier_domain"))
+                        .arg(verifier_domain_arg())
+                        .arg(revocation_list_arg())
+                        .arg(
+                            Arg::with_name("holder_secret")
+                                .help("Path to the holder_secret file")
+                                .long("holder-secret")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("challenge_path")
+                                .help("Path to the challenge.json issued by the verifier")
+                                .long("challenge-path")
+                                .takes_value(true),
+                        )
+                        .arg(native_arg())
+                        .arg(proving_scheme_arg())
+                        .arg(backend_arg())
+                        .arg(curve_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("create_multi_proof")
+                        .about("Generates one proof over several credentials, possibly from different issuers")
+                        .arg(
+                            Arg::with_name("proving_key_path")
+                                .help("Path to the proving key")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("destination_path")
+                                .help("Destination path for the generated proof")
+                                .required(true)
+                                .index(2),
+                        )
+                        .arg(
+                            Arg::with_name("credential")
+                                .help("A credential, its signature and its issuer's public key, in circuit order")
+                                .long("credential")
+                                .value_names(&["credential_path", "signature_path", "issuer_public_key_path"])
+                                .number_of_values(3)
+                                .multiple_occurrences(true)
+                                .required(true),
+                        )
+                        .arg(proving_scheme_arg())
+                        .arg(backend_arg())
+                        .arg(curve_arg())
+                        .arg(reveal_of_arg())
+                        .arg(equal_arg())
+                        .arg(holder_binding_arg().requires("holder_secret"))
+                        .arg(
+                            Arg::with_name("holder_secret")
+                                .help("Path to the holder_secret file")
+                                .long("holder-secret")
+                                .takes_value(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("setup_multi")
+                        .about("Compiles a multi-credential circuit for the prover")
+                        .arg(credentials_arg())
+                        .arg(proving_scheme_arg())
+                        .arg(backend_arg())
+                        .arg(curve_arg())
+                        .arg(reveal_of_arg())
+                        .arg(equal_arg())
+                        .arg(holder_binding_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("create_holder_secret")
+                        .about("Creates a holder secret and prints its commitment for issuance")
+                        .arg(
+                            Arg::with_name("destination_path")
+                                .help("The destination path for the holder_secret file")
+                                .required(true)
+                                .index(1),
                         ),
                 )
                 .subcommand(
                     SubCommand::with_name("setup")
-                        .about("Performs setup operations for the prover"),
+                        .about("Performs setup operations for the prover")
+                        .arg(reveal_arg())
+                        .arg(use_birth_date_arg())
+                        .arg(check_revocation_arg())
+                        .arg(issuer_set_arg())
+                        .arg(holder_binding_arg())
+                        .arg(challenge_arg())
+                        .arg(nullifier_arg())
+                        .arg(pseudonym_arg())
+                        .arg(native_arg())
+                        .arg(proving_scheme_arg())
+                        .arg(backend_arg())
+                        .arg(curve_arg()),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("ceremony")
+                .about("Multi-party trusted setup (MPC phase 2) for a verifier circuit")
+                .subcommand(
+                    SubCommand::with_name("init")
+                        .about("Compiles the circuit and creates the initial mpc.params")
+                        .arg(
+                            Arg::with_name("phase1_path")
+                                .help("Path to the phase 1 powers of tau file (phase1radix2m<n>)")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(reveal_arg())
+                        .arg(use_birth_date_arg())
+                        .arg(check_revocation_arg())
+                        .arg(issuer_set_arg())
+                        .arg(holder_binding_arg())
+                        .arg(challenge_arg())
+                        .arg(nullifier_arg())
+                        .arg(pseudonym_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("contribute")
+                        .about("Adds a contribution with fresh random entropy")
+                        .arg(
+                            Arg::with_name("params_path")
+                                .help("Path to the current parameters")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("output_path")
+                                .help("Path to write the new parameters")
+                                .required(true)
+                                .index(2),
+                        )
+                        .arg(
+                            Arg::with_name("name")
+                                .help("Name of the participant, recorded in the transcript")
+                                .long("name")
+                                .takes_value(true)
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("beacon")
+                        .about("Applies a public random beacon after the last contribution")
+                        .arg(
+                            Arg::with_name("params_path")
+                                .help("Path to the current parameters")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("output_path")
+                                .help("Path to write the new parameters")
+                                .required(true)
+                                .index(2),
+                        )
+                        .arg(
+                            Arg::with_name("beacon_hash")
+                                .help("Public random value, 32 bytes of hex (e.g. a future block hash)")
+                                .long("beacon-hash")
+                                .takes_value(true)
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("iterations")
+                                .help("Number of hash iterations as a power of two")
+                                .long("iterations")
+                                .takes_value(true)
+                                .default_value("10"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("verify")
+                        .about("Checks the transcript and every contribution with zokrates mpc verify")
+                        .arg(
+                            Arg::with_name("params_path")
+                                .help("Path to the final parameters")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("phase1_path")
+                                .help("Path to the phase 1 file used by init")
+                                .required(true)
+                                .index(2),
+                        )
+                        .arg(reveal_arg())
+                        .arg(use_birth_date_arg())
+                        .arg(check_revocation_arg())
+                        .arg(issuer_set_arg())
+                        .arg(holder_binding_arg())
+                        .arg(challenge_arg())
+                        .arg(nullifier_arg())
+                        .arg(pseudonym_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("export_keys")
+                        .alias("export-keys")
+                        .about("Verifies the ceremony and exports the proving and verification keys")
+                        .arg(
+                            Arg::with_name("params_path")
+                                .help("Path to the final parameters")
+                                .required(true)
+                                .index(1),
+                        )
+                        .arg(
+                            Arg::with_name("phase1_path")
+                                .help("Path to the phase 1 file used by init")
+                                .required(true)
+                                .index(2),
+                        )
+                        .arg(reveal_arg())
+                        .arg(use_birth_date_arg())
+                        .arg(check_revocation_arg())
+                        .arg(issuer_set_arg())
+                        .arg(holder_binding_arg())
+                        .arg(challenge_arg())
+                        .arg(nullifier_arg())
+                        .arg(pseudonym_arg()),
                 ),
         )
         .get_matches();
+    // 발급자의 해시 계산은 $PATH의 zokrates 실행 파일로 수행
+    let backend = ZokratesCli::default();
     if let Some(matches) = matches.subcommand_matches("issuer") {
         if let Some(create_matches) = matches.subcommand_matches("create_credential") {
             let credential_id = create_matches
@@ -139,12 +974,19 @@ fn main() {
                 .expect("Missing credential_id");
             println!("Credential ID: {:?}", credential_id);
 
+            let subject_id = create_matches
+                .value_of("subject_did")
+                .expect("Missing subject did");
             let name = create_matches.value_of("name").expect("Missing name");
             let age = create_matches
                 .value_of("age")
                 .expect("Missing age")
                 .parse::<u8>()
-                .expect("Age must be a number");
+                .unwrap_or_else(|_| invalid_input("Age must be a number"));
+            let birth_date = create_matches.value_of("birth_date").map(|birth_date| {
+                NaiveDate::parse_from_str(birth_date, "%Y-%m-%d")
+                    .unwrap_or_else(|_| invalid_input("Birth date must be in YYYY-MM-DD format"))
+            });
             let student_number = create_matches
                 .value_of("student_number")
                 .expect("Missing student_number");
@@ -157,16 +999,50 @@ fn main() {
                     .expect("Missing signature_save_path"),
             );
 
-            issuer::create_credential(
-                credential_id,
+            let holder_commitment =
+                create_matches
+                    .value_of("holder_commitment")
+                    .map(|commitment| {
+                        commitment.parse::<u128>().unwrap_or_else(|_| {
+                            invalid_input("Holder commitment must be a decimal number")
+                        })
+                    });
+
+            let request = CredentialRequest {
+                subject_id,
                 name,
                 age,
+                birth_date,
                 student_number,
                 department,
+                holder_commitment,
+            };
+            issuer::create_credential(
+                &backend,
+                credential_id,
+                &request,
+                &issuer_profile(create_matches),
                 signature_save_path,
-            );
+            )
+            .unwrap_or_else(fail);
         } else if matches.subcommand_matches("setup").is_some() {
-            issuer::setup();
+            issuer::setup(&backend).unwrap_or_else(fail);
+        } else if let Some(revoke_matches) = matches.subcommand_matches("revoke") {
+            issuer::revoke(revoke_matches.value_of("credential_id").unwrap()).unwrap_or_else(fail);
+        } else if let Some(publish_matches) = matches.subcommand_matches("publish_revocation_root")
+        {
+            let destination_path =
+                to_absolute_path(publish_matches.value_of("destination_path").unwrap());
+            issuer::publish_revocation_root(&destination_path).unwrap_or_else(fail);
+        } else if let Some(export_matches) = matches.subcommand_matches("export_public_key") {
+            let destination_path =
+                to_absolute_path(export_matches.value_of("destination_path").unwrap());
+            issuer::export_public_key(&destination_path).unwrap_or_else(fail);
+        } else if let Some(publish_matches) = matches.subcommand_matches("publish_status_list") {
+            let destination_path =
+                to_absolute_path(publish_matches.value_of("destination_path").unwrap());
+            issuer::publish_status_list(&issuer_profile(publish_matches), &destination_path)
+                .unwrap_or_else(fail);
         }
     } else if let Some(matches) = matches.subcommand_matches("verifier") {
         if let Some(get_pk_matches) = matches.subcommand_matches("get_proving_key") {
@@ -175,10 +1051,30 @@ fn main() {
                     .value_of("destination_path")
                     .expect("Missing destination path"),
             );
-            verifier::get_proving_key(destination_path);
+            derive_keys(get_pk_matches, &circuit_spec(get_pk_matches));
+            verifier::get_proving_key(destination_path, &circuit_spec(get_pk_matches))
+                .unwrap_or_else(fail);
             println!("Copying proving key to: {}", destination_path);
-        } else if matches.subcommand_matches("setup").is_some() {
-            verifier::setup();
+        } else if let Some(srs_matches) = matches.subcommand_matches("universal_setup") {
+            let system = ProvingSystem::new(
+                "marlin",
+                srs_matches.value_of("backend").unwrap(),
+                srs_matches.value_of("curve").unwrap(),
+            )
+            .unwrap_or_else(fail);
+            let size = srs_matches
+                .value_of("size")
+                .unwrap()
+                .parse::<u32>()
+                .unwrap_or_else(|_| invalid_input("Size must be a number"));
+            verifier::universal_setup(&ZokratesCli::new(system), size).unwrap_or_else(fail);
+            println!(
+                "Universal setup saved to: {}",
+                verifier::UNIVERSAL_SETUP_PATH
+            );
+        } else if let Some(setup_matches) = matches.subcommand_matches("setup") {
+            verifier::setup(&*zk_backend(setup_matches), &circuit_spec(setup_matches))
+                .unwrap_or_else(fail);
         } else if let Some(cv_contract_matches) = matches.subcommand_matches("get_verify_contract")
         {
             let destination_path = &to_absolute_path(
@@ -187,7 +1083,222 @@ fn main() {
                     .expect("Missing destination path for contract export"),
             );
             println!("Exporting verifier contract to: {}", destination_path);
-            verifier::get_verify_contract(destination_path);
+            derive_keys(cv_contract_matches, &circuit_spec(cv_contract_matches));
+            verifier::get_verify_contract(destination_path, &circuit_spec(cv_contract_matches))
+                .unwrap_or_else(fail);
+        } else if let Some(check_matches) = matches.subcommand_matches("check_min_age") {
+            let proof_path = to_absolute_path(check_matches.value_of("proof_path").unwrap());
+            let min_age = check_matches
+                .value_of("min_age")
+                .unwrap()
+                .parse::<u8>()
+                .unwrap_or_else(|_| invalid_input("Minimum age must be a number"));
+            if verifier::check_min_age(&proof_path, min_age).unwrap_or_else(fail) {
+                println!("Proof attests to age >= {}", min_age);
+            } else {
+                eprintln!("Proof does not attest to age >= {}", min_age);
+                process::exit(1);
+            }
+        } else if let Some(verify_matches) = matches.subcommand_matches("verify") {
+            let proof_path = to_absolute_path(verify_matches.value_of("proof_path").unwrap());
+            let proof = Proof::load(&proof_path).unwrap_or_else(fail);
+            let key_path = match verify_matches.value_of("verification_key") {
+                Some(path) => to_absolute_path(path),
+                None => circuit_spec(verify_matches)
+                    .dir("./zok/verifier")
+                    .join("verification.key")
+                    .to_str()
+                    .unwrap()
+                    .to_owned(),
+            };
+            // 증명에 기록된 증명 시스템으로 검증 (Groth16(bn128)은 zokrates 없이 검증)
+            let system = ProvingSystem {
+                scheme: proof.scheme.clone(),
+                backend: proof.backend.clone().unwrap_or_else(|| "ark".to_owned()),
+                curve: proof.curve.clone(),
+            };
+            if verifier::verify(&ZokratesCli::new(system), Path::new(&key_path), &proof)
+                .unwrap_or_else(fail)
+            {
+                println!("Proof is valid");
+            } else {
+                eprintln!("Proof is invalid");
+                process::exit(1);
+            }
+        } else if let Some(check_matches) = matches.subcommand_matches("check_presentation") {
+            let presentation_path =
+                to_absolute_path(check_matches.value_of("presentation_path").unwrap());
+            let min_age = check_matches
+                .value_of("min_age")
+                .unwrap()
+                .parse::<u8>()
+                .unwrap_or_else(|_| invalid_input("Minimum age must be a number"));
+            let time_tolerance = check_matches
+                .value_of("time_tolerance")
+                .unwrap()
+                .parse::<i64>()
+                .unwrap_or_else(|_| invalid_input("Time tolerance must be a number"));
+            let presentation = Presentation::load(&presentation_path).unwrap_or_else(fail);
+            let revocation_list = check_matches
+                .value_of("revocation_list")
+                .map(|path| RevocationList::load(&to_absolute_path(path)).unwrap_or_else(fail));
+            let trusted_issuers = check_matches
+                .value_of("trusted_issuers")
+                .map(|path| TrustedIssuers::load(&to_absolute_path(path)).unwrap_or_else(fail));
+            let spec = circuit_spec(check_matches);
+            let mut challenges = spec
+                .challenge
+                .then(|| ChallengeStore::load(verifier::CHALLENGE_STORE_PATH).unwrap_or_else(fail));
+            let mut nullifiers = spec
+                .nullifier
+                .then(|| NullifierStore::load(verifier::NULLIFIER_STORE_PATH).unwrap_or_else(fail));
+            let mut context = VerifierContext {
+                revocation_list: revocation_list.as_ref(),
+                issuer_public_key: None,
+                trusted_issuers: trusted_issuers.as_ref(),
+                challenges: challenges.as_mut(),
+                scope: check_matches.value_of("scope"),
+                nullifiers: nullifiers.as_mut(),
+                verifier_domain: check_matches.value_of("verifier_domain"),
+            };
+            // 증명을 검증한 뒤에만 챌린지와 널리파이어가 기록되므로 그때만 저장합니다.
+            if verifier::check_presentation(
+                &ZokratesCli::new(proving_system(check_matches)),
+                &spec.dir("./zok/verifier").join("verification.key"),
+                &presentation,
+                min_age,
+                &spec,
+                time_tolerance,
+                &mut context,
+            )
+            .unwrap_or_else(fail)
+            {
+                if let Some(challenges) = &challenges {
+                    challenges
+                        .save(verifier::CHALLENGE_STORE_PATH)
+                        .unwrap_or_else(fail);
+                }
+                if let Some(nullifiers) = &nullifiers {
+                    nullifiers
+                        .save(verifier::NULLIFIER_STORE_PATH)
+                        .unwrap_or_else(fail);
+                }
+                println!("Proof is valid and the presentation matches its public inputs");
+                if let Some(pseudonym) = &presentation.pseudonym {
+                    println!("Pseudonym: {}", pseudonym);
+                }
+                for (attribute, value) in &presentation.disclosed {
+                    println!("Disclosed {}: {}", attribute, value);
+                }
+            } else {
+                eprintln!("Proof is invalid or the presentation does not match its public inputs");
+                process::exit(1);
+            }
+        } else if let Some(resolve_matches) = matches.subcommand_matches("resolve_did") {
+            let did = resolve_matches.value_of("did").unwrap();
+            match did::resolve(did) {
+                Some(document) => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&document).expect("Failed to serialize document")
+                ),
+                None => {
+                    eprintln!("Not a BabyJubJub did:key: {}", did);
+                    process::exit(1);
+                }
+            }
+        } else if let Some(build_matches) = matches.subcommand_matches("build_trusted_issuers") {
+            let keys_dir = to_absolute_path(build_matches.value_of("keys_dir").unwrap());
+            let destination_path =
+                to_absolute_path(build_matches.value_of("destination_path").unwrap());
+            let issuers =
+                verifier::build_trusted_issuers(&keys_dir, &destination_path).unwrap_or_else(fail);
+            println!(
+                "Trusted issuer root: {} {}",
+                issuers.root[0], issuers.root[1]
+            );
+        } else if let Some(setup_matches) = matches.subcommand_matches("setup_multi") {
+            verifier::setup(
+                &ZokratesCli::new(proving_system(setup_matches)),
+                &multi_spec(setup_matches, credential_count(setup_matches)),
+            )
+            .unwrap_or_else(fail);
+        } else if let Some(get_pk_matches) = matches.subcommand_matches("get_multi_proving_key") {
+            let destination_path =
+                to_absolute_path(get_pk_matches.value_of("destination_path").unwrap());
+            let spec = multi_spec(get_pk_matches, credential_count(get_pk_matches));
+            derive_keys(get_pk_matches, &spec);
+            verifier::get_proving_key(&destination_path, &spec).unwrap_or_else(fail);
+        } else if let Some(check_matches) = matches.subcommand_matches("check_multi_presentation") {
+            let presentation = MultiPresentation::load(&to_absolute_path(
+                check_matches.value_of("presentation_path").unwrap(),
+            ))
+            .unwrap_or_else(fail);
+            let time_tolerance = check_matches
+                .value_of("time_tolerance")
+                .unwrap()
+                .parse::<i64>()
+                .unwrap_or_else(|_| invalid_input("Time tolerance must be a number"));
+            let trusted_issuers = match check_matches.values_of("trusted_issuer") {
+                Some(paths) => paths
+                    .map(|path| eddsa::load_public_key(&to_absolute_path(path)))
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap_or_else(fail),
+                None => vec![ISSUER_PUBLIC_KEY.map(str::to_owned)],
+            };
+            let spec = multi_spec(check_matches, credential_count(check_matches));
+            if verifier::check_multi_presentation(
+                &ZokratesCli::new(proving_system(check_matches)),
+                &spec.dir("./zok/verifier").join("verification.key"),
+                &presentation,
+                &spec,
+                &trusted_issuers,
+                time_tolerance,
+            )
+            .unwrap_or_else(fail)
+            {
+                println!("Proof is valid and the presentation matches its public inputs");
+                for (i, disclosed) in presentation.disclosed.iter().enumerate() {
+                    for (attribute, value) in disclosed {
+                        println!("Disclosed {}.{}: {}", i, attribute, value);
+                    }
+                }
+            } else {
+                eprintln!("Proof is invalid or the presentation does not match its public inputs");
+                process::exit(1);
+            }
+        } else if let Some(challenge_matches) = matches.subcommand_matches("create_challenge") {
+            let destination_path =
+                to_absolute_path(challenge_matches.value_of("destination_path").unwrap());
+            let lifetime = challenge_matches
+                .value_of("lifetime")
+                .unwrap()
+                .parse::<i64>()
+                .unwrap_or_else(|_| invalid_input("Lifetime must be a number"));
+            verifier::create_challenge(
+                challenge_matches.value_of("verifier_id").unwrap(),
+                lifetime,
+                &destination_path,
+            )
+            .unwrap_or_else(fail);
+        } else if let Some(status_matches) = matches.subcommand_matches("check_status") {
+            let credential = Credential::load(&to_absolute_path(
+                status_matches.value_of("credential_path").unwrap(),
+            ))
+            .unwrap_or_else(fail);
+            let status_list = StatusList::load(&to_absolute_path(
+                status_matches.value_of("status_list_path").unwrap(),
+            ))
+            .unwrap_or_else(fail);
+            if verifier::check_status(
+                &credential.claim.credential_status,
+                &status_list,
+                &ISSUER_PUBLIC_KEY.map(str::to_owned),
+            ) {
+                println!("Credential is not revoked");
+            } else {
+                eprintln!("Credential is revoked or its status could not be verified");
+                process::exit(1);
+            }
         }
     } else if let Some(matches) = matches.subcommand_matches("prover") {
         if let Some(proof_matches) = matches.subcommand_matches("create_proof") {
@@ -199,15 +1310,149 @@ fn main() {
                 to_absolute_path(proof_matches.value_of("proving_key_path").unwrap());
             let destination_path =
                 to_absolute_path(proof_matches.value_of("destination_path").unwrap());
+            let min_age = proof_matches
+                .value_of("min_age")
+                .unwrap()
+                .parse::<u8>()
+                .unwrap_or_else(|_| invalid_input("Minimum age must be a number"));
+            let revocation_list_path = proof_matches
+                .value_of("revocation_list")
+                .map(to_absolute_path);
+            let trusted_issuers_path = proof_matches
+                .value_of("trusted_issuers")
+                .map(to_absolute_path);
+            let holder_secret_path = proof_matches
+                .value_of("holder_secret")
+                .map(to_absolute_path);
+            let challenge_path = proof_matches
+                .value_of("challenge_path")
+                .map(to_absolute_path);
+            let options = ProofOptions {
+                revocation_list_path: revocation_list_path.as_deref(),
+                trusted_issuers_path: trusted_issuers_path.as_deref(),
+                holder_secret_path: holder_secret_path.as_deref(),
+                challenge_path: challenge_path.as_deref(),
+                scope: proof_matches.value_of("scope"),
+                verifier_domain: proof_matches.value_of("verifier_domain"),
+            };
 
             prover::create_proof(
+                &*zk_backend(proof_matches),
                 &credential_path,
                 &signature_path,
                 &proving_key_path,
+                min_age,
+                &circuit_spec(proof_matches),
+                &options,
                 &destination_path,
-            );
-        } else if matches.subcommand_matches("setup").is_some() {
-            prover::setup();
+            )
+            .unwrap_or_else(fail);
+        } else if let Some(proof_matches) = matches.subcommand_matches("create_multi_proof") {
+            let proving_key_path =
+                to_absolute_path(proof_matches.value_of("proving_key_path").unwrap());
+            let destination_path =
+                to_absolute_path(proof_matches.value_of("destination_path").unwrap());
+            let paths = proof_matches
+                .values_of("credential")
+                .unwrap()
+                .map(to_absolute_path)
+                .collect::<Vec<_>>();
+            let inputs = paths
+                .chunks(3)
+                .map(|input| CredentialInput {
+                    credential_path: &input[0],
+                    signature_path: &input[1],
+                    issuer_public_key_path: &input[2],
+                })
+                .collect::<Vec<_>>();
+            let holder_secret_path = proof_matches
+                .value_of("holder_secret")
+                .map(to_absolute_path);
+
+            prover::create_multi_proof(
+                &ZokratesCli::new(proving_system(proof_matches)),
+                &inputs,
+                &proving_key_path,
+                &multi_spec(proof_matches, inputs.len()),
+                holder_secret_path.as_deref(),
+                &destination_path,
+            )
+            .unwrap_or_else(fail);
+        } else if let Some(setup_matches) = matches.subcommand_matches("setup_multi") {
+            prover::setup(
+                &ZokratesCli::new(proving_system(setup_matches)),
+                &multi_spec(setup_matches, credential_count(setup_matches)),
+            )
+            .unwrap_or_else(fail);
+        } else if let Some(secret_matches) = matches.subcommand_matches("create_holder_secret") {
+            let destination_path =
+                to_absolute_path(secret_matches.value_of("destination_path").unwrap());
+            let secret = holder::create_secret(&destination_path).unwrap_or_else(fail);
+            println!("Holder commitment: {}", holder::holder_commitment(secret));
+            println!("Holder DID: {}", holder::holder_did(secret));
+        } else if let Some(setup_matches) = matches.subcommand_matches("setup") {
+            prover::setup(&*zk_backend(setup_matches), &circuit_spec(setup_matches))
+                .unwrap_or_else(fail);
+        }
+    } else if let Some(matches) = matches.subcommand_matches("ceremony") {
+        // 회로와 키는 ./zok/verifier 아래에 둡니다. (MPC는 bellman 백엔드의 Groth16만 지원합니다.)
+        let config = Config::new("./zok");
+        if let Some(init_matches) = matches.subcommand_matches("init") {
+            let phase1_path = to_absolute_path(init_matches.value_of("phase1_path").unwrap());
+            let params_path = ceremony::init(&config, &circuit_spec(init_matches), &phase1_path)
+                .unwrap_or_else(fail);
+            println!("Initial parameters saved to: {}", params_path);
+        } else if let Some(contribute_matches) = matches.subcommand_matches("contribute") {
+            let params_path = to_absolute_path(contribute_matches.value_of("params_path").unwrap());
+            let output_path = to_absolute_path(contribute_matches.value_of("output_path").unwrap());
+            let hash = ceremony::contribute(
+                &config,
+                &params_path,
+                &output_path,
+                contribute_matches.value_of("name").unwrap(),
+            )
+            .unwrap_or_else(fail);
+            println!("Contribution saved to: {}", output_path);
+            println!("Parameters hash: {}", hash);
+        } else if let Some(beacon_matches) = matches.subcommand_matches("beacon") {
+            let params_path = to_absolute_path(beacon_matches.value_of("params_path").unwrap());
+            let output_path = to_absolute_path(beacon_matches.value_of("output_path").unwrap());
+            let iterations = beacon_matches
+                .value_of("iterations")
+                .unwrap()
+                .parse::<u32>()
+                .unwrap_or_else(|_| invalid_input("Iterations must be a number"));
+            let hash = ceremony::beacon(
+                &config,
+                &params_path,
+                &output_path,
+                beacon_matches.value_of("beacon_hash").unwrap(),
+                iterations,
+            )
+            .unwrap_or_else(fail);
+            println!("Beacon applied: {}", output_path);
+            println!("Parameters hash: {}", hash);
+        } else if let Some(verify_matches) = matches.subcommand_matches("verify") {
+            let params_path = to_absolute_path(verify_matches.value_of("params_path").unwrap());
+            let phase1_path = to_absolute_path(verify_matches.value_of("phase1_path").unwrap());
+            ceremony::verify(
+                &config,
+                &circuit_spec(verify_matches),
+                &params_path,
+                &phase1_path,
+            )
+            .unwrap_or_else(fail);
+            println!("Ceremony is valid");
+        } else if let Some(export_matches) = matches.subcommand_matches("export_keys") {
+            let params_path = to_absolute_path(export_matches.value_of("params_path").unwrap());
+            let phase1_path = to_absolute_path(export_matches.value_of("phase1_path").unwrap());
+            ceremony::export_keys(
+                &config,
+                &circuit_spec(export_matches),
+                &params_path,
+                &phase1_path,
+            )
+            .unwrap_or_else(fail);
         }
     } else {
         eprintln!("Invalid command");
@@ -19,14 +19,16 @@ fn main() {
     // 모든 필요한 디렉토리를 생성합니다.
     for dir in &[&target_issuer_dir, &target_prover_dir, &target_verifier_dir] {
         if !dir.exists() {
-            fs::create_dir_all(dir).expect(&format!("Failed to create directory: {:?}", dir));
+            fs::create_dir_all(dir)
+                .unwrap_or_else(|_| panic!("Failed to create directory: {:?}", dir));
         }
     }
 
     let source_files = [
         ("zok/create_hash.zok", &target_zok_dir),
-        ("zok/verify_credential.zok", &target_prover_dir),
-        ("zok/verify_credential.zok", &target_verifier_dir),
+        ("zok/merkle.zok", &target_zok_dir),
+        ("zok/eddsa.zok", &target_zok_dir),
+        ("zok/holder.zok", &target_zok_dir),
         ("zok/create_signature.py", &target_issuer_dir),
     ];
 
@@ -0,0 +1,573 @@
+use crate::circuit::CircuitSpec;
+use crate::error::{
+    create_dir, load_json, path_str, read_file, save_json, write_file, CredentialError, Result,
+};
+use crate::groth16::{encode_verification_key, render_verifier, VerificationKey};
+use crate::proof::Proof;
+use crate::proving_system::{universal_setup_path, ProvingSystem};
+use crate::r1cs;
+use crate::verifier::verify_proof;
+use ark_bn254::Bn254;
+use ark_groth16::ProvingKey;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use std::{
+    cell::RefCell,
+    fs::File,
+    io::{self, BufReader, BufWriter},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    rc::Rc,
+};
+
+// 회로 컴파일, 설정, 증인 계산, 증명 생성과 검증을 맡는 증명 시스템
+// 파일 이름은 ZoKrates CLI를 따릅니다: 회로 디렉토리의 out, witness, proving.key, verification.key, verifier.sol
+pub trait ZkBackend {
+    // <circuit_dir>/<source>를 컴파일하여 <circuit_dir>/out 생성
+    fn compile(&self, circuit_dir: &Path, source: &str) -> Result<()>;
+    // <circuit_dir>/proving.key와 verification.key 생성
+    // 범용 설정 방식(marlin)은 universal_setup_path(circuit_dir)의 SRS에서 키를 유도합니다.
+    fn setup(&self, circuit_dir: &Path) -> Result<()>;
+    // 범용 설정 방식에서 모든 회로가 공유하는 SRS(최대 2^size 제약)를 path에 생성
+    fn universal_setup(&self, path: &Path, size: u32) -> Result<()>;
+    // main 함수 인자 순서로 나열한 값으로 <circuit_dir>/witness를 만들고 회로의 출력을 반환
+    // 회로의 assert를 만족하지 않는 값이면 CredentialError::UnsatisfiedPredicate
+    fn compute_witness(&self, circuit_dir: &Path, args: &[String]) -> Result<Vec<String>>;
+    // <circuit_dir>/witness로 증명을 만들어 proof_path에 저장
+    fn generate_proof(
+        &self,
+        circuit_dir: &Path,
+        proving_key_path: &str,
+        proof_path: &str,
+    ) -> Result<()>;
+    // 증명이 유효하지 않으면 Ok(false), 키나 증명을 읽을 수 없으면 Err
+    fn verify(&self, verification_key_path: &str, proof_path: &str) -> Result<bool>;
+    // <circuit_dir>/verifier.sol 생성
+    fn export_verifier(&self, circuit_dir: &Path) -> Result<()>;
+    // 설정과 증명에 사용하는 증명 방식, 백엔드, 곡선
+    fn proving_system(&self) -> ProvingSystem;
+}
+
+// 범용 설정의 기본 크기 (2^n 제약), 설정할 회로들보다 커야 합니다.
+pub const UNIVERSAL_SETUP_SIZE: u32 = 22;
+
+// 기본 zokrates 실행 파일 ($PATH에서 찾습니다.)
+pub const ZOKRATES: &str = "zokrates";
+
+// zokrates 실행 파일을 호출하는 백엔드
+#[derive(Debug, Clone)]
+pub struct ZokratesCli {
+    pub system: ProvingSystem,
+    // 실행 파일 경로 (기본값: $PATH의 zokrates)
+    pub binary: PathBuf,
+}
+
+impl Default for ZokratesCli {
+    fn default() -> ZokratesCli {
+        ZokratesCli::new(ProvingSystem::default())
+    }
+}
+
+impl ZokratesCli {
+    pub fn new(system: ProvingSystem) -> ZokratesCli {
+        ZokratesCli::with_binary(system, Path::new(ZOKRATES))
+    }
+
+    pub fn with_binary(system: ProvingSystem, binary: &Path) -> ZokratesCli {
+        ZokratesCli {
+            system,
+            binary: binary.to_owned(),
+        }
+    }
+}
+
+// 표준 출력은 그대로 보여 주고, 표준 에러 출력은 실패하면 CredentialError::Zokrates에 담습니다.
+pub(crate) fn run_zokrates(binary: &Path, current_dir: &Path, args: &[String]) -> Result<()> {
+    let command = args[0].clone();
+    let output = Command::new(binary)
+        .current_dir(current_dir) // 작업 디렉토리 설정
+        .args(args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::piped())
+        .output() // 명령어 실행
+        .map_err(|error| CredentialError::Zokrates {
+            command: command.clone(),
+            stderr: error.to_string(),
+        })?;
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    if !output.status.success() {
+        return Err(CredentialError::Zokrates { command, stderr });
+    }
+    eprint!("{}", stderr);
+    Ok(())
+}
+
+impl ZkBackend for ZokratesCli {
+    fn compile(&self, circuit_dir: &Path, source: &str) -> Result<()> {
+        run_zokrates(
+            &self.binary,
+            circuit_dir,
+            &[
+                "compile".into(),
+                "-i".into(),
+                source.into(),
+                "--curve".into(),
+                self.system.curve.clone(),
+            ],
+        )
+    }
+
+    fn setup(&self, circuit_dir: &Path) -> Result<()> {
+        let mut command = vec![
+            "setup".into(),
+            "--backend".into(),
+            self.system.backend.clone(),
+            "--proving-scheme".into(),
+            self.system.scheme.clone(),
+        ];
+        if self.system.is_universal() {
+            let srs_path = universal_setup_path(circuit_dir)?;
+            check_universal_setup(&srs_path)?;
+            command.extend(["--universal-setup-path".into(), path_str(&srs_path)?]);
+        }
+        run_zokrates(&self.binary, circuit_dir, &command)
+    }
+
+    fn universal_setup(&self, path: &Path, size: u32) -> Result<()> {
+        if !self.system.is_universal() {
+            return Err(CredentialError::InvalidInput(format!(
+                "{} has no universal setup",
+                self.system.scheme
+            )));
+        }
+        let dir = path.parent().ok_or_else(|| {
+            CredentialError::InvalidInput(format!(
+                "Invalid universal setup path: {}",
+                path.display()
+            ))
+        })?;
+        create_dir(dir)?;
+        run_zokrates(
+            &self.binary,
+            dir,
+            &[
+                "universal-setup".into(),
+                "--curve".into(),
+                self.system.curve.clone(),
+                "--proving-scheme".into(),
+                self.system.scheme.clone(),
+                "--size".into(),
+                size.to_string(),
+                "--universal-setup-path".into(),
+                path_str(path)?,
+            ],
+        )
+    }
+
+    fn compute_witness(&self, circuit_dir: &Path, args: &[String]) -> Result<Vec<String>> {
+        let mut command = vec!["compute-witness".into(), "-a".into()];
+        command.extend_from_slice(args);
+        command.push("--verbose".into());
+        command.push("--json".into());
+        match run_zokrates(&self.binary, circuit_dir, &command) {
+            // 회로의 assert 실패: "Execution failed: Assertion failed ..."
+            Err(CredentialError::Zokrates { stderr, .. })
+                if stderr.contains("Assertion failed") =>
+            {
+                return Err(CredentialError::UnsatisfiedPredicate(format!(
+                    "Credential does not satisfy the circuit: {}",
+                    stderr.trim()
+                )));
+            }
+            result => result?,
+        }
+
+        // witness.json에서 회로의 출력(`~out_0`, `~out_1`, ...)을 순서대로 추출
+        let witness: Map<String, Value> = load_json("witness", circuit_dir.join("witness.json"))?;
+        Ok((0..)
+            .map_while(|i| match witness.get(&format!("~out_{}", i)) {
+                Some(Value::String(value)) => Some(value.clone()),
+                _ => None,
+            })
+            .collect())
+    }
+
+    fn generate_proof(
+        &self,
+        circuit_dir: &Path,
+        proving_key_path: &str,
+        proof_path: &str,
+    ) -> Result<()> {
+        run_zokrates(
+            &self.binary,
+            circuit_dir,
+            &[
+                "generate-proof".into(),
+                "--backend".into(),
+                self.system.backend.clone(),
+                "--proving-scheme".into(),
+                self.system.scheme.clone(),
+                "--proving-key-path".into(),
+                proving_key_path.into(),
+                "--witness".into(),
+                "witness".into(), // compute-witness에서 생성된 증인 파일의 이름
+                "--proof-path".into(),
+                proof_path.into(),
+            ],
+        )
+    }
+
+    // Groth16(bn128) 증명은 zokrates 없이 프로세스 안에서 검증합니다. (verifier::verify_proof)
+    // zokrates verify는 검증에 실패해도 FAILED를 출력하고 0으로 끝나므로 출력의 PASSED로 판단하며,
+    // 0이 아닌 코드로 끝나면(키나 증명을 읽을 수 없는 경우 등) Err입니다.
+    fn verify(&self, verification_key_path: &str, proof_path: &str) -> Result<bool> {
+        if self.system.scheme == "g16" && self.system.curve == "bn128" {
+            return verify_proof(
+                &Proof::load(proof_path)?,
+                &VerificationKey::load(verification_key_path)?,
+            );
+        }
+        let output = Command::new(&self.binary)
+            .args(["verify", "-v", verification_key_path, "-j", proof_path])
+            .args(["--backend", &self.system.backend])
+            .output()
+            .map_err(|error| CredentialError::Zokrates {
+                command: "verify".to_owned(),
+                stderr: error.to_string(),
+            })?;
+        if !output.status.success() {
+            return Err(CredentialError::Zokrates {
+                command: "verify".to_owned(),
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            });
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        print!("{}", stdout);
+        Ok(stdout.contains("PASSED"))
+    }
+
+    fn export_verifier(&self, circuit_dir: &Path) -> Result<()> {
+        run_zokrates(&self.binary, circuit_dir, &["export-verifier".into()])
+    }
+
+    fn proving_system(&self) -> ProvingSystem {
+        self.system.clone()
+    }
+}
+
+// ZoKrates 없이 arkworks로 구성한 회로(r1cs::CredentialCircuit)로 Groth16 설정, 증명, 검증을 하는 백엔드
+// 회로는 spec으로 정해지므로 .zok 소스는 컴파일하지 않습니다.
+// verification.key와 proof.json은 ZoKrates 형식으로 쓰며, proving.key는 arkworks 직렬화 형식입니다.
+#[derive(Debug, Clone)]
+pub struct ArkBackend {
+    pub spec: CircuitSpec,
+}
+
+impl ArkBackend {
+    pub fn new(spec: &CircuitSpec) -> Result<ArkBackend> {
+        if !r1cs::supports(spec) {
+            return Err(CredentialError::InvalidInput(
+                "The native circuit supports only --reveal and --use-birth-date".to_owned(),
+            ));
+        }
+        Ok(ArkBackend { spec: spec.clone() })
+    }
+}
+
+impl ZkBackend for ArkBackend {
+    fn compile(&self, _circuit_dir: &Path, _source: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn setup(&self, circuit_dir: &Path) -> Result<()> {
+        let proving_key = r1cs::setup(&self.spec)?;
+        let key_path = circuit_dir.join("proving.key");
+        let file =
+            File::create(&key_path).map_err(|error| CredentialError::io(&key_path, error))?;
+        proving_key
+            .serialize_uncompressed(BufWriter::new(file))
+            .map_err(|error| CredentialError::io(&key_path, io::Error::other(error)))?;
+        let key = encode_verification_key(&proving_key.vk);
+        save_json(circuit_dir.join("verification.key"), &key)
+    }
+
+    fn universal_setup(&self, _path: &Path, _size: u32) -> Result<()> {
+        Err(CredentialError::InvalidInput(
+            "The native circuit uses Groth16, which has no universal setup".to_owned(),
+        ))
+    }
+
+    // 인자는 증명할 때 회로에 할당하도록 witness에 기록합니다. (지원하는 구성에는 출력이 없습니다.)
+    fn compute_witness(&self, circuit_dir: &Path, args: &[String]) -> Result<Vec<String>> {
+        let witness = serde_json::to_string(args).expect("Failed to serialize witness");
+        write_file(circuit_dir.join("witness"), witness)?;
+        Ok(Vec::new())
+    }
+
+    fn generate_proof(
+        &self,
+        circuit_dir: &Path,
+        proving_key_path: &str,
+        proof_path: &str,
+    ) -> Result<()> {
+        let args: Vec<String> = load_json("witness", circuit_dir.join("witness"))?;
+        let file = File::open(proving_key_path)
+            .map_err(|error| CredentialError::io(proving_key_path, error))?;
+        let proving_key =
+            ProvingKey::<Bn254>::deserialize_uncompressed_unchecked(BufReader::new(file))
+                .map_err(|error| CredentialError::parse("proving key", error))?;
+        let proof = r1cs::prove(&self.spec, &proving_key, &args)?;
+        save_json(proof_path, &proof)
+    }
+
+    fn verify(&self, verification_key_path: &str, proof_path: &str) -> Result<bool> {
+        verify_proof(
+            &Proof::load(proof_path)?,
+            &VerificationKey::load(verification_key_path)?,
+        )
+    }
+
+    // verifier.sol은 ZoKrates 없이 verification.key로 생성합니다.
+    fn export_verifier(&self, circuit_dir: &Path) -> Result<()> {
+        let key_path = circuit_dir.join("verification.key");
+        if !key_path.exists() {
+            return Err(CredentialError::MissingSetup(
+                "Verification key not found. Setup first".to_owned(),
+            ));
+        }
+        let key: VerificationKey = load_json("verification key", key_path)?;
+        write_file(circuit_dir.join("verifier.sol"), render_verifier(&key)?)
+    }
+
+    // r1cs 회로는 BN254 위의 Groth16으로만 증명합니다.
+    fn proving_system(&self) -> ProvingSystem {
+        ProvingSystem::default()
+    }
+}
+
+fn digest(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update((part.len() as u64).to_be_bytes());
+        hasher.update(part.as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+// 범용 설정 방식의 키 유도 전에 SRS가 있는지 확인
+pub(crate) fn check_universal_setup(srs_path: &Path) -> Result<()> {
+    if !srs_path.exists() {
+        return Err(CredentialError::MissingSetup(
+            "Universal setup not found. Run universal_setup first".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+// ZoKrates 없이 흐름을 테스트하기 위한 결정적 백엔드
+// 같은 파일 이름으로 해시만 기록하며, 증명의 inputs에는 증인 계산에 쓴 모든 인자를 그대로 담습니다.
+// 영지식성도 건전성도 없으므로 테스트에만 사용해야 합니다.
+// 복제본은 호출 기록을 공유하므로 Prover, Verifier에 넘긴 뒤에도 호출을 확인할 수 있습니다.
+#[derive(Debug, Default, Clone)]
+pub struct MockBackend {
+    // compute_witness가 반환할 회로의 출력
+    pub outputs: Vec<String>,
+    // 키와 증명에 기록할 증명 시스템
+    pub system: ProvingSystem,
+    calls: Rc<RefCell<Vec<String>>>,
+}
+
+impl MockBackend {
+    pub fn with_outputs(outputs: Vec<String>) -> MockBackend {
+        MockBackend {
+            outputs,
+            ..MockBackend::default()
+        }
+    }
+
+    pub fn with_system(system: ProvingSystem) -> MockBackend {
+        MockBackend {
+            system,
+            ..MockBackend::default()
+        }
+    }
+
+    // 호출한 명령어 기록 (예: "compile verify_credential.zok")
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.borrow().clone()
+    }
+
+    fn record(&self, call: String) {
+        self.calls.borrow_mut().push(call);
+    }
+}
+
+impl ZkBackend for MockBackend {
+    fn compile(&self, circuit_dir: &Path, source: &str) -> Result<()> {
+        self.record(format!("compile {}", source));
+        let program = read_file(circuit_dir.join(source))?;
+        write_file(circuit_dir.join("out"), digest(&[&program]))
+    }
+
+    fn setup(&self, circuit_dir: &Path) -> Result<()> {
+        self.record("setup".to_owned());
+        if self.system.is_universal() {
+            check_universal_setup(&universal_setup_path(circuit_dir)?)?;
+        }
+        let circuit = read_file(circuit_dir.join("out"))?;
+        write_file(circuit_dir.join("proving.key"), &circuit)?;
+        let key = json!({
+            "scheme": self.system.scheme,
+            "curve": self.system.curve,
+            "circuit": circuit,
+        });
+        write_file(circuit_dir.join("verification.key"), key.to_string())
+    }
+
+    fn universal_setup(&self, path: &Path, size: u32) -> Result<()> {
+        self.record(format!("universal-setup {}", size));
+        let srs = json!({"scheme": self.system.scheme, "size": size});
+        create_dir(path.parent().unwrap())?;
+        write_file(path, srs.to_string())
+    }
+
+    fn compute_witness(&self, circuit_dir: &Path, args: &[String]) -> Result<Vec<String>> {
+        self.record(format!("compute-witness {}", args.len()));
+        let circuit = read_file(circuit_dir.join("out"))?;
+        let witness = json!({"circuit": circuit, "args": args});
+        write_file(circuit_dir.join("witness"), witness.to_string())?;
+        Ok(self.outputs.clone())
+    }
+
+    fn generate_proof(
+        &self,
+        circuit_dir: &Path,
+        proving_key_path: &str,
+        proof_path: &str,
+    ) -> Result<()> {
+        self.record("generate-proof".to_owned());
+        let witness: Value = load_json("witness", circuit_dir.join("witness"))?;
+        let circuit = read_file(proving_key_path)?;
+        if witness["circuit"] != circuit {
+            return Err(CredentialError::InvalidInput(
+                "Proving key does not match the circuit".to_owned(),
+            ));
+        }
+        let inputs: Vec<String> = serde_json::from_value(witness["args"].clone()).unwrap();
+        let proof = Proof {
+            scheme: self.system.scheme.clone(),
+            curve: self.system.curve.clone(),
+            backend: Some(self.system.backend.clone()),
+            proof: json!({ "digest": digest(&[&circuit, &inputs.join(" ")]) }),
+            inputs,
+        };
+        save_json(proof_path, &proof)
+    }
+
+    fn verify(&self, verification_key_path: &str, proof_path: &str) -> Result<bool> {
+        self.record("verify".to_owned());
+        let key: Value = load_json("verification key", verification_key_path)?;
+        let proof = Proof::load(proof_path)?;
+        let circuit = key["circuit"].as_str().unwrap_or_default();
+        Ok(proof.scheme == key["scheme"]
+            && proof.proof["digest"] == digest(&[circuit, &proof.inputs.join(" ")]))
+    }
+
+    fn export_verifier(&self, circuit_dir: &Path) -> Result<()> {
+        self.record("export-verifier".to_owned());
+        let circuit = read_file(circuit_dir.join("out"))?;
+        write_file(
+            circuit_dir.join("verifier.sol"),
+            format!("// mock verifier for circuit {}\n", circuit),
+        )
+    }
+
+    fn proving_system(&self) -> ProvingSystem {
+        self.system.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn mock_backend_test() {
+        let circuit_dir = std::env::temp_dir().join("mock_backend_test");
+        fs::create_dir_all(&circuit_dir).unwrap();
+        fs::write(circuit_dir.join("main.zok"), "def main(field x) {}").unwrap();
+        let pk_path = circuit_dir.join("proving.key");
+        let vk_path = circuit_dir.join("verification.key");
+        let proof_path = circuit_dir.join("proof.json");
+        let [pk_path, vk_path, proof_path] =
+            [pk_path, vk_path, proof_path].map(|path| path.to_str().unwrap().to_owned());
+
+        let backend = MockBackend::with_outputs(vec!["7".to_owned()]);
+        backend.compile(&circuit_dir, "main.zok").unwrap();
+        backend.setup(&circuit_dir).unwrap();
+        backend.export_verifier(&circuit_dir).unwrap();
+        let args = ["1".to_owned(), "2".to_owned()];
+        assert_eq!(backend.compute_witness(&circuit_dir, &args).unwrap(), ["7"]);
+        backend
+            .generate_proof(&circuit_dir, &pk_path, &proof_path)
+            .unwrap();
+        let proof = fs::read_to_string(&proof_path).unwrap();
+        assert!(backend.verify(&vk_path, &proof_path).unwrap());
+
+        // 같은 입력이면 같은 증명
+        backend
+            .generate_proof(&circuit_dir, &pk_path, &proof_path)
+            .unwrap();
+        assert_eq!(fs::read_to_string(&proof_path).unwrap(), proof);
+
+        fs::write(&proof_path, proof.replace("\"2\"", "\"3\"")).unwrap();
+        assert!(!backend.verify(&vk_path, &proof_path).unwrap());
+        assert_eq!(
+            backend.calls(),
+            [
+                "compile main.zok",
+                "setup",
+                "export-verifier",
+                "compute-witness 2",
+                "generate-proof",
+                "verify",
+                "generate-proof",
+                "verify"
+            ]
+        );
+        fs::remove_dir_all(&circuit_dir).unwrap();
+    }
+
+    // zokrates verify처럼 검증 결과와 관계없이 0으로 끝나는 실행 파일로 gm17 증명 검증
+    #[cfg(unix)]
+    #[test]
+    fn zokrates_verify_output_test() {
+        use crate::workspace::Workspace;
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = Workspace::temp().unwrap();
+        let binary = dir.path().join("zokrates");
+        fs::write(
+            &binary,
+            "#!/bin/sh\nif grep -q tampered \"$5\"; then echo FAILED; else echo PASSED; fi\n",
+        )
+        .unwrap();
+        fs::set_permissions(&binary, fs::Permissions::from_mode(0o755)).unwrap();
+        let [vk_path, proof_path] = ["verification.key", "proof.json"]
+            .map(|name| dir.path().join(name).to_str().unwrap().to_owned());
+        fs::write(&vk_path, "{}").unwrap();
+
+        let system = ProvingSystem::new("gm17", "ark", "bn128").unwrap();
+        let backend = ZokratesCli::with_binary(system, &binary);
+        fs::write(&proof_path, r#"{"proof": "valid"}"#).unwrap();
+        assert!(backend.verify(&vk_path, &proof_path).unwrap());
+        fs::write(&proof_path, r#"{"proof": "tampered"}"#).unwrap();
+        assert!(!backend.verify(&vk_path, &proof_path).unwrap());
+
+        // 실행 자체가 실패하면 Err
+        let missing = ZokratesCli::with_binary(backend.system.clone(), &dir.path().join("none"));
+        assert!(missing.verify(&vk_path, &proof_path).is_err());
+    }
+}
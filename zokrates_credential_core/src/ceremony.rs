@@ -0,0 +1,367 @@
+use crate::backend::{run_zokrates, ZkBackend, ZokratesCli};
+use crate::circuit::Circuit;
+use crate::config::{install_libraries, Config};
+use crate::error::{copy_file, load_json, path_str, save_json, CredentialError, Result};
+use crate::proving_system::{proving_key_path, ProvingSystem};
+use crate::verifier::export_contracts;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{fs::File, io, path::Path};
+
+// ZoKrates의 MPC phase 2는 bellman 백엔드의 Groth16(bn128)만 지원합니다.
+pub fn ceremony_system() -> ProvingSystem {
+    ProvingSystem::new("g16", "bellman", "bn128").unwrap()
+}
+
+// 설정의 zokrates 실행 파일로 세레모니의 증명 시스템을 쓰는 백엔드
+fn ceremony_backend(config: &Config) -> ZokratesCli {
+    ZokratesCli::with_binary(ceremony_system(), &config.zokrates)
+}
+
+// 세레모니의 한 단계: 초기화(init), 참여자의 기여(contribute), 무작위 비컨(beacon)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Contribution {
+    pub kind: String,
+    // contribute: 참여자 이름
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub participant: Option<String>,
+    // beacon: 공개된 무작위 값(32바이트 16진수)과 반복 횟수 (2^n)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beacon_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iterations: Option<u32>,
+    // 이 단계의 입력 params의 SHA-256 (init은 회로 out의 해시)
+    pub previous_hash: String,
+    // 이 단계가 만든 params의 SHA-256
+    pub params_hash: String,
+}
+
+// params 파일과 함께 전달되는 해시 기록 (<params>.transcript.json)
+// 누구나 params 파일의 해시를 다시 계산하여 기여의 순서와 연결을 오프라인으로 확인할 수 있습니다.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Transcript {
+    // 회로(out)와 phase 1 (powers of tau) 파일의 SHA-256
+    pub circuit_hash: String,
+    pub phase1_hash: String,
+    pub contributions: Vec<Contribution>,
+}
+
+impl Transcript {
+    pub fn load(params_path: &str) -> Result<Transcript> {
+        load_json("ceremony transcript", transcript_path(params_path))
+    }
+
+    pub fn save(&self, params_path: &str) -> Result<()> {
+        save_json(transcript_path(params_path), self)
+    }
+
+    // output_path에 새로 만든 params를 기록
+    fn append(&mut self, mut contribution: Contribution, output_path: &str) -> Result<()> {
+        contribution.previous_hash = match self.contributions.last() {
+            Some(last) => last.params_hash.clone(),
+            None => self.circuit_hash.clone(),
+        };
+        contribution.params_hash = file_hash(output_path)?;
+        self.contributions.push(contribution);
+        Ok(())
+    }
+
+    // 해시 연결 확인
+    // - 첫 단계는 회로에서 시작한 init이고, 이후 단계는 앞 단계가 만든 params에서 시작해야 합니다.
+    // - 마지막 단계가 만든 params가 params_path의 파일이어야 합니다.
+    pub fn verify(&self, params_path: &str) -> Result<()> {
+        let mut previous = &self.circuit_hash;
+        for (i, contribution) in self.contributions.iter().enumerate() {
+            if (i == 0) != (contribution.kind == "init") {
+                return Err(invalid(format!(
+                    "Unexpected {} at step {}",
+                    contribution.kind, i
+                )));
+            }
+            if &contribution.previous_hash != previous {
+                return Err(invalid(format!(
+                    "Step {} does not continue the previous step",
+                    i
+                )));
+            }
+            previous = &contribution.params_hash;
+        }
+        if self.contributions.is_empty() || previous != &file_hash(params_path)? {
+            return Err(invalid(
+                "Parameters do not match the last step of the transcript",
+            ));
+        }
+        Ok(())
+    }
+}
+
+// 세레모니 기록이나 params가 맞지 않음
+fn invalid(message: impl Into<String>) -> CredentialError {
+    CredentialError::InvalidInput(format!("Ceremony is invalid: {}", message.into()))
+}
+
+pub fn transcript_path(params_path: &str) -> String {
+    format!("{}.transcript.json", params_path)
+}
+
+// 파일의 SHA-256 (16진수), params 파일은 크므로 나누어 읽습니다.
+pub fn file_hash(path: &str) -> Result<String> {
+    let mut file = File::open(path).map_err(|error| CredentialError::io(path, error))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher).map_err(|error| CredentialError::io(path, error))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn step(kind: &str) -> Contribution {
+    Contribution {
+        kind: kind.to_owned(),
+        participant: None,
+        beacon_hash: None,
+        iterations: None,
+        previous_hash: String::new(),
+        params_hash: String::new(),
+    }
+}
+
+// 세레모니는 설정(Config)의 keys_dir 아래 회로 구성별 디렉토리에서 설정의 zokrates로 진행합니다.
+// 회로를 컴파일하고 phase 1 파일(phase1radix2m<n>)로 <circuit_dir>/mpc.params를 만들어 경로를 반환
+pub fn init(config: &Config, spec: &impl Circuit, phase1_path: &str) -> Result<String> {
+    install_libraries(&config.keys_dir)?;
+    let circuit_dir = spec.prepare(&config.keys_dir)?;
+    ceremony_backend(config).compile(&circuit_dir, "verify_credential.zok")?;
+    run_zokrates(
+        &config.zokrates,
+        &circuit_dir,
+        &[
+            "mpc".into(),
+            "init".into(),
+            "-i".into(),
+            "out".into(),
+            "-o".into(),
+            "mpc.params".into(),
+            "-r".into(),
+            phase1_path.into(),
+        ],
+    )?;
+
+    let params_path = path_str(&circuit_dir.join("mpc.params"))?;
+    let mut transcript = Transcript {
+        circuit_hash: file_hash(&path_str(&circuit_dir.join("out"))?)?,
+        phase1_hash: file_hash(phase1_path)?,
+        contributions: Vec::new(),
+    };
+    transcript.append(step("init"), &params_path)?;
+    transcript.save(&params_path)?;
+    Ok(params_path)
+}
+
+// 참여자의 기여: 무작위 엔트로피로 params를 갱신하여 output_path에 저장하고 새 params의 해시를 반환
+// 엔트로피는 저장하지 않으며, 참여자 중 한 명이라도 엔트로피를 버리면 키를 위조할 수 없습니다.
+pub fn contribute(
+    config: &Config,
+    params_path: &str,
+    output_path: &str,
+    participant: &str,
+) -> Result<String> {
+    let mut transcript = Transcript::load(params_path)?;
+    transcript.verify(params_path)?;
+    let entropy = rand::thread_rng()
+        .gen::<[u8; 32]>()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+    run_zokrates(
+        &config.zokrates,
+        Path::new("."),
+        &[
+            "mpc".into(),
+            "contribute".into(),
+            "-i".into(),
+            params_path.into(),
+            "-o".into(),
+            output_path.into(),
+            "-e".into(),
+            entropy,
+        ],
+    )?;
+
+    transcript.append(
+        Contribution {
+            participant: Some(participant.to_owned()),
+            ..step("contribute")
+        },
+        output_path,
+    )?;
+    transcript.save(output_path)?;
+    Ok(transcript.contributions.last().unwrap().params_hash.clone())
+}
+
+// 마지막 기여 이후에 공개된 무작위 값(예: 정해진 블록의 해시)을 적용
+pub fn beacon(
+    config: &Config,
+    params_path: &str,
+    output_path: &str,
+    beacon_hash: &str,
+    iterations: u32,
+) -> Result<String> {
+    if beacon_hash.len() != 64 || !beacon_hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(CredentialError::InvalidInput(
+            "Beacon hash must be 32 bytes of hex".to_owned(),
+        ));
+    }
+    let mut transcript = Transcript::load(params_path)?;
+    transcript.verify(params_path)?;
+    run_zokrates(
+        &config.zokrates,
+        Path::new("."),
+        &[
+            "mpc".into(),
+            "beacon".into(),
+            "-i".into(),
+            params_path.into(),
+            "-o".into(),
+            output_path.into(),
+            "-h".into(),
+            beacon_hash.into(),
+            "-n".into(),
+            iterations.to_string(),
+        ],
+    )?;
+
+    transcript.append(
+        Contribution {
+            beacon_hash: Some(beacon_hash.to_owned()),
+            iterations: Some(iterations),
+            ..step("beacon")
+        },
+        output_path,
+    )?;
+    transcript.save(output_path)?;
+    Ok(transcript.contributions.last().unwrap().params_hash.clone())
+}
+
+// 기록의 해시 연결과 회로, phase 1 파일을 확인한 뒤 `zokrates mpc verify`로 모든 기여를 검증
+// zokrates는 기여마다 BLAKE2b 해시를 출력하므로, 참여자는 기여할 때 출력된 해시와 비교할 수 있습니다.
+pub fn verify(
+    config: &Config,
+    spec: &impl Circuit,
+    params_path: &str,
+    phase1_path: &str,
+) -> Result<()> {
+    let circuit_dir = spec.dir(&config.keys_dir);
+    let transcript = Transcript::load(params_path)?;
+    transcript.verify(params_path)?;
+    if transcript.circuit_hash != file_hash(&path_str(&circuit_dir.join("out"))?)? {
+        return Err(invalid("Transcript was started for a different circuit"));
+    }
+    if transcript.phase1_hash != file_hash(phase1_path)? {
+        return Err(invalid(
+            "Transcript was started from a different phase 1 file",
+        ));
+    }
+    run_zokrates(
+        &config.zokrates,
+        &circuit_dir,
+        &[
+            "mpc".into(),
+            "verify".into(),
+            "-i".into(),
+            params_path.into(),
+            "-c".into(),
+            "out".into(),
+            "-r".into(),
+            phase1_path.into(),
+        ],
+    )
+}
+
+// 검증한 params에서 proving.key와 verification.key를 내보내고 verifier.sol을 생성
+// 기록은 <circuit_dir>/ceremony.json으로 함께 공개합니다.
+pub fn export_keys(
+    config: &Config,
+    spec: &impl Circuit,
+    params_path: &str,
+    phase1_path: &str,
+) -> Result<()> {
+    verify(config, spec, params_path, phase1_path)?;
+    let circuit_dir = spec.dir(&config.keys_dir);
+    run_zokrates(
+        &config.zokrates,
+        &circuit_dir,
+        &[
+            "mpc".into(),
+            "export".into(),
+            "-i".into(),
+            params_path.into(),
+        ],
+    )?;
+    ceremony_system().save_for_key(&proving_key_path(&circuit_dir)?)?;
+    copy_file(
+        transcript_path(params_path),
+        circuit_dir.join("ceremony.json"),
+    )?;
+    export_contracts(&ceremony_backend(config), spec, &circuit_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn transcript_test() {
+        let dir = std::env::temp_dir().join("transcript_test");
+        fs::create_dir_all(&dir).unwrap();
+        let path = |name: &str| path_str(&dir.join(name)).unwrap();
+        fs::write(path("out"), "circuit").unwrap();
+        fs::write(path("mpc.params"), "params 0").unwrap();
+        fs::write(path("alice.params"), "params 1").unwrap();
+        fs::write(path("final.params"), "params 2").unwrap();
+
+        let mut transcript = Transcript {
+            circuit_hash: file_hash(&path("out")).unwrap(),
+            phase1_hash: String::new(),
+            contributions: Vec::new(),
+        };
+        transcript
+            .append(step("init"), &path("mpc.params"))
+            .unwrap();
+        transcript
+            .append(
+                Contribution {
+                    participant: Some("alice".to_owned()),
+                    ..step("contribute")
+                },
+                &path("alice.params"),
+            )
+            .unwrap();
+        transcript
+            .append(step("beacon"), &path("final.params"))
+            .unwrap();
+        transcript.save(&path("final.params")).unwrap();
+
+        let loaded = Transcript::load(&path("final.params")).unwrap();
+        assert_eq!(loaded, transcript);
+        assert!(loaded.verify(&path("final.params")).is_ok());
+        assert_eq!(
+            loaded.contributions[1].previous_hash,
+            file_hash(&path("mpc.params")).unwrap()
+        );
+        // 마지막 단계가 아닌 params, 바뀐 params
+        assert!(loaded.verify(&path("alice.params")).is_err());
+        fs::write(path("final.params"), "params 2'").unwrap();
+        assert!(loaded.verify(&path("final.params")).is_err());
+        fs::write(path("final.params"), "params 2").unwrap();
+
+        // 기여를 빼거나 init이 아닌 단계로 시작하는 기록
+        let mut skipped = transcript.clone();
+        skipped.contributions.remove(1);
+        assert!(skipped.verify(&path("final.params")).is_err());
+        let mut reordered = transcript.clone();
+        reordered.contributions[0].kind = "contribute".to_owned();
+        assert!(reordered.verify(&path("final.params")).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
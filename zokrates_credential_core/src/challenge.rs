@@ -0,0 +1,101 @@
+use crate::error::{load_json, save_json, CredentialError, Result};
+use crate::proof::text_field;
+use chrono::Utc;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+// 검증자가 발행하는 챌린지: 증명의 공개 입력으로 들어가 다른 검증자나 재전송을 막습니다.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Challenge {
+    pub verifier_id: String,
+    // 128비트 난수 (10진수)
+    pub nonce: String,
+    // 만료 시각 (Unix timestamp)
+    pub expires: i64,
+}
+
+// 검증자 id를 회로에 넣을 128비트 값으로 변환
+pub fn verifier_field(verifier_id: &str) -> u128 {
+    text_field(verifier_id)
+}
+
+impl Challenge {
+    pub fn new(verifier_id: &str, lifetime: i64) -> Challenge {
+        Challenge {
+            verifier_id: verifier_id.to_owned(),
+            nonce: rand::thread_rng().gen::<u128>().to_string(),
+            expires: Utc::now().timestamp() + lifetime,
+        }
+    }
+
+    pub fn load(challenge_path: &str) -> Result<Challenge> {
+        load_json("challenge", challenge_path)
+    }
+
+    pub fn nonce(&self) -> Result<u128> {
+        self.nonce
+            .parse()
+            .map_err(|_| CredentialError::parse("challenge", "nonce must be a decimal number"))
+    }
+}
+
+// 검증자가 발행했지만 아직 사용되지 않은 챌린지 목록
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ChallengeStore {
+    pub outstanding: Vec<Challenge>,
+}
+
+impl ChallengeStore {
+    // 파일이 없으면 빈 목록
+    pub fn load(store_path: &str) -> Result<ChallengeStore> {
+        if !Path::new(store_path).exists() {
+            return Ok(ChallengeStore::default());
+        }
+        load_json("challenge store", store_path)
+    }
+
+    pub fn save(&self, store_path: &str) -> Result<()> {
+        save_json(store_path, self)
+    }
+
+    // 만료된 챌린지를 정리하고 새 챌린지를 추가
+    pub fn issue(&mut self, verifier_id: &str, lifetime: i64) -> Challenge {
+        let now = Utc::now().timestamp();
+        self.outstanding.retain(|challenge| challenge.expires > now);
+        let challenge = Challenge::new(verifier_id, lifetime);
+        self.outstanding.push(challenge.clone());
+        challenge
+    }
+
+    pub fn is_outstanding(&self, challenge: &Challenge) -> bool {
+        let now = Utc::now().timestamp();
+        self.outstanding
+            .iter()
+            .any(|outstanding| outstanding == challenge && outstanding.expires > now)
+    }
+
+    // 사용한 챌린지는 다시 받아들이지 않습니다.
+    pub fn consume(&mut self, challenge: &Challenge) {
+        self.outstanding
+            .retain(|outstanding| outstanding != challenge);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn challenge_store_test() {
+        let mut store = ChallengeStore::default();
+        let challenge = store.issue("https://verifier.example", 600);
+        let expired = store.issue("https://verifier.example", -1);
+        assert_ne!(challenge.nonce, expired.nonce);
+        assert!(store.is_outstanding(&challenge));
+        assert!(!store.is_outstanding(&expired));
+
+        store.consume(&challenge);
+        assert!(!store.is_outstanding(&challenge));
+    }
+}
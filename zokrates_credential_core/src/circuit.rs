@@ -0,0 +1,964 @@
+use crate::credential::{leaf_index, ATTRIBUTES};
+use crate::error::{create_dir, write_file, CredentialError, Result};
+use crate::merkle::DEPTH;
+use std::path::{Path, PathBuf};
+
+// 공개할 속성 이름을 credential.json의 속성 키로 변환
+// 학과(department)는 alumni_of 해시에 포함되어 있으므로 alumni_of를 공개합니다.
+pub fn parse_attribute(name: &str) -> Option<&'static str> {
+    let name = match name {
+        "department" => "alumni_of",
+        "context" => "@context",
+        other => other,
+    };
+    ATTRIBUTES
+        .iter()
+        .find(|&&attribute| attribute == name)
+        .copied()
+}
+
+// 공개할 속성 목록을 리프 순서로 정렬하고 중복을 제거
+pub fn normalize_reveal(reveal: &[String]) -> Result<Vec<&'static str>> {
+    let mut attributes = reveal
+        .iter()
+        .map(|name| {
+            parse_attribute(name).ok_or_else(|| {
+                CredentialError::InvalidInput(format!("Unknown attribute: {}", name))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    attributes.sort_by_key(|attribute| leaf_index(attribute).ok());
+    attributes.dedup();
+    Ok(attributes)
+}
+
+// 속성 키에 대응하는 zok main 함수의 인자 이름
+// 정수 속성은 값 그대로, 나머지는 해시 값이 들어갑니다.
+fn attribute_param(attribute: &str) -> String {
+    match attribute {
+        "age" | "exp" | "birth_date" | "revocation_index" | "holder_commitment" => {
+            attribute.to_owned()
+        }
+        other => format!("{}_hash", other.trim_start_matches('@')),
+    }
+}
+
+// 고정 공개 입력의 원소 개수 (폐기 레지스트리 루트는 field[2])
+fn public_param_len(name: &str) -> usize {
+    match name {
+        "revocation_root" | "issuer_root" => 2,
+        _ => 1,
+    }
+}
+
+// 증명 회로 구성의 공통 동작: 설정과 증명은 구성별 디렉토리에서 이루어집니다.
+pub trait Circuit {
+    // 회로 구성별 디렉토리
+    fn dir(&self, base_dir: impl AsRef<Path>) -> PathBuf;
+
+    // 구성에 맞는 verify_credential.zok 소스 (만들 수 없는 구성이면 InvalidInput)
+    fn render(&self) -> Result<String>;
+
+    // verifier.sol과 함께 내보낼 래퍼 컨트랙트 (credential_verifier.sol)
+    fn contract(&self) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    // 구성에 맞는 회로 파일을 생성하고 그 디렉토리를 반환
+    fn prepare(&self, base_dir: impl AsRef<Path>) -> Result<PathBuf> {
+        let dir = self.dir(base_dir);
+        create_dir(&dir)?;
+        write_file(dir.join("verify_credential.zok"), self.render()?)?;
+        Ok(dir)
+    }
+}
+
+// 증명 회로의 구성: 같은 구성으로 설정한 증명 키와 검증 키끼리만 호환됩니다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CircuitSpec {
+    // 공개 입력으로 드러낼 속성 (리프 순서)
+    pub reveal: Vec<&'static str>,
+    // 저장된 나이(age) 대신 생년월일(birth_date)로 최소 나이를 검사
+    pub birth_date: bool,
+    // 폐기 레지스트리(공개 루트)에 자격증명이 없음을 증명
+    pub revocation: bool,
+    // 자격증명에 서명된 홀더 커밋먼트의 비밀값을 아는지 증명 (홀더 바인딩)
+    pub holder_binding: bool,
+    // 검증자 챌린지(검증자 id, 논스)를 공개 입력으로 받아 홀더 비밀값에 묶음 (holder_binding 필요)
+    pub challenge: bool,
+    // 스코프(공개 입력)별 널리파이어를 공개 출력으로 계산 (holder_binding 필요)
+    pub nullifier: bool,
+    // 검증자 도메인(공개 입력)별 가명을 공개 출력으로 계산 (holder_binding 필요)
+    pub pseudonym: bool,
+    // 고정된 발급자 키 대신, 서명한 발급자가 신뢰하는 발급자 집합(공개 루트)에 속함을 증명
+    pub issuer_set: bool,
+}
+
+impl CircuitSpec {
+    pub fn new(reveal: &[String], birth_date: bool) -> Result<CircuitSpec> {
+        Ok(CircuitSpec {
+            reveal: normalize_reveal(reveal)?,
+            birth_date,
+            ..CircuitSpec::default()
+        })
+    }
+
+    // 나이 검사에 쓰는 속성
+    fn age_attribute(&self) -> &'static str {
+        if self.birth_date {
+            "birth_date"
+        } else {
+            "age"
+        }
+    }
+
+    // 증명에서 여는 리프: 나이와 만료 검사에 필요한 속성과 공개할 속성들 (리프 순서)
+    pub fn opened_attributes(&self) -> Vec<&'static str> {
+        let mut opened = self.reveal.clone();
+        opened.push(self.age_attribute());
+        opened.push("exp");
+        if self.revocation {
+            opened.push("revocation_index");
+        }
+        if self.holder_binding {
+            opened.push("holder_commitment");
+        }
+        opened.sort_by_key(|attribute| leaf_index(attribute).ok());
+        opened.dedup();
+        opened
+    }
+
+    // 공개 속성보다 앞에 오는 고정 공개 입력 (main 함수 인자 순서)
+    // today: 생년월일 검사에 쓰는 오늘 날짜 (credential::day_number)
+    // revocation_root: 발급자가 공개한 폐기 레지스트리 루트
+    // issuer_root: 검증자가 신뢰하는 발급자 공개키 집합의 루트
+    pub fn public_params(&self) -> Vec<&'static str> {
+        let mut params = vec!["min_age", "current_time"];
+        if self.birth_date {
+            params.push("today");
+        }
+        if self.revocation {
+            params.push("revocation_root");
+        }
+        if self.issuer_set {
+            params.push("issuer_root");
+        }
+        if self.challenge {
+            params.extend(["verifier_id", "nonce"]);
+        }
+        if self.nullifier {
+            params.push("scope");
+        }
+        if self.pseudonym {
+            params.push("verifier_domain");
+        }
+        params
+    }
+
+    // main 함수의 반환 값 (공개 입력 뒤에 기록되는 공개 출력)
+    pub fn outputs(&self) -> Vec<&'static str> {
+        let mut outputs = Vec::new();
+        if self.challenge {
+            outputs.push("challenge_response");
+        }
+        if self.nullifier {
+            outputs.push("nullifier");
+        }
+        if self.pseudonym {
+            outputs.push("pseudonym");
+        }
+        outputs
+    }
+
+    // 증명의 공개 입력 이름 (proof.json inputs 순서)
+    // 배열 인자는 원소마다 "이름[i]"로 나열합니다.
+    pub fn public_inputs(&self) -> Vec<String> {
+        let mut inputs = Vec::new();
+        for name in self.public_params() {
+            match public_param_len(name) {
+                1 => inputs.push(name.to_owned()),
+                len => inputs.extend((0..len).map(|i| format!("{}[{}]", name, i))),
+            }
+        }
+        inputs.extend(self.reveal.iter().map(|name| name.to_string()));
+        inputs.extend(self.outputs().iter().map(|name| name.to_string()));
+        inputs
+    }
+}
+
+impl Circuit for CircuitSpec {
+    // 회로 구성별 디렉토리
+    fn dir(&self, base_dir: impl AsRef<Path>) -> PathBuf {
+        let mut parts = Vec::new();
+        if self.birth_date {
+            parts.push("birth_date".to_owned());
+        }
+        if self.revocation {
+            parts.push("revocation".to_owned());
+        }
+        if self.issuer_set {
+            parts.push("issuer_set".to_owned());
+        }
+        if self.holder_binding {
+            parts.push("holder".to_owned());
+        }
+        if self.challenge {
+            parts.push("challenge".to_owned());
+        }
+        if self.nullifier {
+            parts.push("nullifier".to_owned());
+        }
+        if self.pseudonym {
+            parts.push("pseudonym".to_owned());
+        }
+        if !self.reveal.is_empty() {
+            let names = self
+                .reveal
+                .iter()
+                .map(|attribute| attribute.trim_start_matches('@'))
+                .collect::<Vec<_>>();
+            parts.push(format!("reveal_{}", names.join("_")));
+        }
+        if parts.is_empty() {
+            parts.push("default".to_owned());
+        }
+        base_dir.as_ref().join(parts.join("_"))
+    }
+
+    // 구성에 맞는 verify_credential.zok 소스 생성
+    // 여는 리프마다 값과 머클 경로를 받아 모두 같은 루트로 이어지는지 확인하고,
+    // 그 루트에 대한 발급자의 서명을 검증합니다.
+    // 공개 입력 순서: public_inputs 참고
+    fn render(&self) -> Result<String> {
+        for (enabled, name) in [
+            (self.challenge, "A verifier challenge"),
+            (self.nullifier, "A nullifier"),
+            (self.pseudonym, "A pseudonym"),
+        ] {
+            if enabled && !self.holder_binding {
+                return Err(CredentialError::InvalidInput(format!(
+                    "{} requires holder binding",
+                    name
+                )));
+            }
+        }
+        let mut params = self
+            .public_params()
+            .iter()
+            .map(|&name| match public_param_len(name) {
+                1 => format!("field {}", name),
+                len => format!("field[{}] {}", len, name),
+            })
+            .collect::<Vec<_>>();
+        let mut body = Vec::new();
+        for (i, attribute) in self.opened_attributes().iter().enumerate() {
+            let param = attribute_param(attribute);
+            let visibility = if self.reveal.contains(attribute) {
+                ""
+            } else {
+                "private "
+            };
+            params.push(format!("{}field {}", visibility, param));
+            params.push(format!("private field[DEPTH][2] {}_path", param));
+
+            let index = leaf_index(attribute)?;
+            let directions = (0..DEPTH)
+                .map(|level| ((index >> level) & 1 == 1).to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let root = if i == 0 {
+                "root".to_owned()
+            } else {
+                format!("{}_root", param)
+            };
+            body.push(format!(
+                "    field[2] {} = merkleRoot({}, [{}], {}, {}_path);",
+                root, index, directions, param, param
+            ));
+            if i > 0 {
+                body.push(format!(
+                    "    assert({}[0] == root[0] && {}[1] == root[1]);",
+                    root, root
+                ));
+            }
+        }
+        let mut imports = vec!["DEPTH", "merkleRoot"];
+        if self.revocation {
+            params.push("private field[REVOCATION_DEPTH][2] revocation_path".to_owned());
+            imports.extend(["REVOCATION_DEPTH", "nonRevokedRoot"]);
+            body.push(String::new());
+            body.push(
+                "    // 폐기 인덱스의 리프가 폐기 레지스트리(공개 루트)에서 비어 있는지 검사"
+                    .to_owned(),
+            );
+            body.push(
+                "    field[2] registry_root = nonRevokedRoot(revocation_index, revocation_path);"
+                    .to_owned(),
+            );
+            body.push("    assert(registry_root[0] == revocation_root[0] && registry_root[1] == revocation_root[1]);".to_owned());
+        }
+        // 서명을 검증할 발급자 공개키: 발급자 집합의 한 원소이거나 고정된 키
+        let issuer_key = if self.issuer_set {
+            params.extend([
+                "private field[2] issuer_key".to_owned(),
+                "private field issuer_index".to_owned(),
+                "private field[ISSUER_SET_DEPTH][2] issuer_path".to_owned(),
+            ]);
+            imports.extend(["ISSUER_SET_DEPTH", "issuerSetRoot"]);
+            body.push(String::new());
+            body.push(
+                "    // 서명한 발급자의 공개키가 신뢰하는 발급자 집합(공개 루트)에 있는지 검사"
+                    .to_owned(),
+            );
+            body.push(
+                "    field[2] set_root = issuerSetRoot(issuer_key, issuer_index, issuer_path);"
+                    .to_owned(),
+            );
+            body.push(
+                "    assert(set_root[0] == issuer_root[0] && set_root[1] == issuer_root[1]);"
+                    .to_owned(),
+            );
+            "issuer_key"
+        } else {
+            "ISSUER_PUBLIC_KEY"
+        };
+        let mut holder_import = String::new();
+        if self.holder_binding {
+            params.push("private field holder_secret".to_owned());
+            let mut functions = vec!["holderCommitment"];
+            if self.challenge {
+                functions.push("challengeResponse");
+            }
+            if self.nullifier {
+                functions.push("scopedNullifier");
+            }
+            if self.pseudonym {
+                functions.push("verifierPseudonym");
+            }
+            holder_import = format!("from \"../../holder\" import {};\n", functions.join(", "));
+            body.push(String::new());
+            body.push("    // 자격증명의 홀더 커밋먼트에 대한 비밀값을 아는지 검사".to_owned());
+            body.push(
+                "    assert(holderCommitment(holder_secret) == holder_commitment);".to_owned(),
+            );
+        }
+        params.push("private field[2] R".to_owned());
+        params.push("private field S".to_owned());
+
+        // 공개 출력: 반환 값이 있으면 main의 반환 타입도 함께 정합니다.
+        let mut values = Vec::new();
+        if self.challenge {
+            body.push(String::new());
+            body.push("    // 검증자 챌린지를 홀더 비밀값에 묶은 응답 (공개 출력)".to_owned());
+            body.push("    field challenge_response = challengeResponse(holder_secret, verifier_id, nonce);".to_owned());
+            values.push("challenge_response");
+        }
+        if self.nullifier {
+            body.push(String::new());
+            body.push("    // 스코프별 널리파이어: 같은 홀더가 같은 스코프에서 두 번 증명하면 같은 값 (공개 출력)".to_owned());
+            body.push("    field nullifier = scopedNullifier(holder_secret, scope);".to_owned());
+            values.push("nullifier");
+        }
+        if self.pseudonym {
+            body.push(String::new());
+            body.push(
+                "    // 검증자 도메인별 가명: 같은 검증자에게만 같은 값 (공개 출력)".to_owned(),
+            );
+            body.push(
+                "    field pseudonym = verifierPseudonym(holder_secret, verifier_domain);"
+                    .to_owned(),
+            );
+            values.push("pseudonym");
+        }
+        let (return_type, returns) = match values.len() {
+            0 => (String::new(), "\n    return;\n".to_owned()),
+            1 => (
+                " -> field".to_owned(),
+                format!("\n    return {};\n", values[0]),
+            ),
+            n => (
+                format!(" -> ({})", vec!["field"; n].join(", ")),
+                format!("\n    return ({});\n", values.join(", ")),
+            ),
+        };
+
+        let age_check = if self.birth_date {
+            // 4 * 경과 일수 >= 1461 * 최소 나이 (1년 = 365.25일)
+            // 실제 생일보다 늦게 통과할 수는 있어도(최대 하루) 일찍 통과하지는 않습니다.
+            "    // 오늘(공개 입력)까지 생년월일로부터 최소 나이(공개 입력)만큼의 햇수가 지났는지 검사
+    assert(today >= birth_date);
+    assert(4 * (today - birth_date) >= 1461 * min_age);"
+        } else {
+            "    // 나이가 검증자가 요구한 최소 나이(공개 입력) 이상인지 검사
+    assert(age >= min_age);"
+        };
+
+        Ok(format!(
+            r#"import "utils/pack/u32/nonStrictUnpack256" as unpack256u;
+from "../../eddsa" import {eddsa_imports};
+from "../../merkle" import {imports};
+{holder_import}
+// 이 파일은 circuit.rs에서 생성됩니다.
+def main({params}){return_type} {{
+    // 여는 리프들이 모두 같은 머클 루트로 이어지는지 검사
+{body}
+
+    // 루트의 각 field 요소를 u32[8] 타입으로 변환
+    u32[8] M0 = unpack256u(root[0]);
+    u32[8] M1 = unpack256u(root[1]);
+
+    // Eddsa 서명 검증
+    // 서명 :(R, S), 공개키 : A, Memo : M0, M1
+    bool signature_valid = verifyEddsa(R, S, {issuer_key}, M0, M1, BABYJUBJUB_PARAMS);
+    assert(signature_valid == true);
+
+{age_check}
+
+    // 자격증명이 증명 시각(공개 입력)에 아직 만료되지 않았는지 검사
+    assert(exp > current_time);
+{returns}}}
+"#,
+            eddsa_imports = if self.issuer_set {
+                "BABYJUBJUB_PARAMS, verifyEddsa"
+            } else {
+                "BABYJUBJUB_PARAMS, ISSUER_PUBLIC_KEY, verifyEddsa"
+            },
+            imports = imports.join(", "),
+            holder_import = holder_import,
+            params = params.join(", "),
+            body = body.join("\n"),
+            issuer_key = issuer_key,
+            age_check = age_check,
+            return_type = return_type,
+            returns = returns,
+        ))
+    }
+
+    // 널리파이어 회로는 사용한 널리파이어를 기록하는 래퍼 컨트랙트를 함께 생성
+    fn contract(&self) -> Result<Option<String>> {
+        self.nullifier.then(|| self.render_contract()).transpose()
+    }
+}
+
+impl CircuitSpec {
+    // 널리파이어 회로용 Solidity 래퍼 (credential_verifier.sol)
+    // verifier.sol(`zokrates export-verifier` 또는 groth16::render_verifier)의 verifyTx로 증명을 검증하고,
+    // 스코프별로 사용한 널리파이어를 기록하여 같은 널리파이어를 다시 받아들이지 않습니다.
+    pub fn render_contract(&self) -> Result<String> {
+        if !self.nullifier {
+            return Err(CredentialError::InvalidInput(
+                "The contract wrapper requires a nullifier circuit".to_owned(),
+            ));
+        }
+        let inputs = self.public_inputs();
+        let position = |name: &str| {
+            inputs
+                .iter()
+                .position(|input| input == name)
+                .ok_or_else(|| CredentialError::InvalidInput(format!("No public input: {}", name)))
+        };
+        Ok(format!(
+            r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "./verifier.sol";
+
+// 이 파일은 circuit.rs에서 생성됩니다.
+contract CredentialVerifier {{
+    Verifier public immutable verifier;
+
+    // scope => nullifier => 사용 여부
+    mapping(uint256 => mapping(uint256 => bool)) public nullifierUsed;
+
+    event NullifierUsed(uint256 indexed scope, uint256 indexed nullifier);
+
+    constructor(address verifierAddress) {{
+        verifier = Verifier(verifierAddress);
+    }}
+
+    // 증명이 유효하고 널리파이어가 처음 사용된 경우에만 기록합니다.
+    function verifyAndUse(Verifier.Proof memory proof, uint[{count}] memory input) public returns (bool) {{
+        uint256 scope = input[{scope}];
+        uint256 nullifier = input[{nullifier}];
+        require(!nullifierUsed[scope][nullifier], "Nullifier already used");
+        require(verifier.verifyTx(proof, input), "Invalid proof");
+        nullifierUsed[scope][nullifier] = true;
+        emit NullifierUsed(scope, nullifier);
+        return true;
+    }}
+}}
+"#,
+            count = inputs.len(),
+            scope = position("scope")?,
+            nullifier = position("nullifier")?,
+        ))
+    }
+}
+
+// 여러 자격증명(발급자가 다를 수 있음)을 하나의 증명으로 묶는 회로 구성
+// 자격증명마다 발급자 공개키(공개 입력)에 대한 서명과 만료를 검사하고,
+// 자격증명 사이의 제약(같은 속성 값, 같은 홀더 비밀값)을 함께 증명합니다.
+// 모든 자격증명은 같은 리프 배치(credential::ATTRIBUTES)를 따라야 합니다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MultiCircuitSpec {
+    // 자격증명별로 공개할 속성 (리프 순서), 자격증명 수는 reveal의 길이
+    pub reveal: Vec<Vec<&'static str>>,
+    // 모든 자격증명에서 값이 같아야 하는 속성 (예: 주체 id)
+    pub equal: Vec<&'static str>,
+    // 하나의 홀더 비밀값이 모든 자격증명의 홀더 커밋먼트와 맞는지 증명
+    pub holder_binding: bool,
+}
+
+impl MultiCircuitSpec {
+    pub fn new(
+        reveal: &[Vec<String>],
+        equal: &[String],
+        holder_binding: bool,
+    ) -> Result<MultiCircuitSpec> {
+        if reveal.len() < 2 {
+            return Err(CredentialError::InvalidInput(
+                "A multi-credential proof needs at least two credentials".to_owned(),
+            ));
+        }
+        Ok(MultiCircuitSpec {
+            reveal: reveal
+                .iter()
+                .map(|names| normalize_reveal(names))
+                .collect::<Result<_>>()?,
+            equal: normalize_reveal(equal)?,
+            holder_binding,
+        })
+    }
+
+    // 증명에 들어가는 자격증명 수
+    pub fn count(&self) -> usize {
+        self.reveal.len()
+    }
+
+    // i번째 자격증명에서 여는 리프: 만료 검사, 자격증명 사이의 제약, 공개에 필요한 속성 (리프 순서)
+    pub fn opened_attributes(&self, i: usize) -> Vec<&'static str> {
+        let mut opened = self.reveal[i].clone();
+        opened.push("exp");
+        opened.extend(self.equal.iter().copied());
+        if self.holder_binding {
+            opened.push("holder_commitment");
+        }
+        opened.sort_by_key(|attribute| leaf_index(attribute).ok());
+        opened.dedup();
+        opened
+    }
+
+    // 증명의 공개 입력 이름 (proof.json inputs 순서)
+    // 발급자 공개키는 "issuer_keys[i][j]", 공개 속성은 "i.속성"으로 나열합니다.
+    pub fn public_inputs(&self) -> Vec<String> {
+        let mut inputs = vec!["current_time".to_owned()];
+        for i in 0..self.count() {
+            inputs.extend((0..2).map(|j| format!("issuer_keys[{}][{}]", i, j)));
+        }
+        for (i, reveal) in self.reveal.iter().enumerate() {
+            inputs.extend(reveal.iter().map(|name| format!("{}.{}", i, name)));
+        }
+        inputs
+    }
+}
+
+impl Circuit for MultiCircuitSpec {
+    // multi_<자격증명 수>[_holder][_equal_<속성>][_<i>_reveal_<속성>]
+    fn dir(&self, base_dir: impl AsRef<Path>) -> PathBuf {
+        let names = |attributes: &[&str]| {
+            attributes
+                .iter()
+                .map(|attribute| attribute.trim_start_matches('@'))
+                .collect::<Vec<_>>()
+                .join("_")
+        };
+        let mut parts = vec![format!("multi_{}", self.count())];
+        if self.holder_binding {
+            parts.push("holder".to_owned());
+        }
+        if !self.equal.is_empty() {
+            parts.push(format!("equal_{}", names(&self.equal)));
+        }
+        for (i, reveal) in self.reveal.iter().enumerate() {
+            if !reveal.is_empty() {
+                parts.push(format!("{}_reveal_{}", i, names(reveal)));
+            }
+        }
+        base_dir.as_ref().join(parts.join("_"))
+    }
+
+    // 자격증명마다 여는 리프들이 같은 루트로 이어지는지, 그 루트에 대한 발급자 서명이 유효한지,
+    // 만료되지 않았는지 검사한 뒤 자격증명 사이의 제약을 검사합니다.
+    // 공개 입력 순서: public_inputs 참고
+    fn render(&self) -> Result<String> {
+        let count = self.count();
+        let mut params = vec![
+            "field current_time".to_owned(),
+            format!("field[{}][2] issuer_keys", count),
+        ];
+        let mut body = Vec::new();
+        for i in 0..count {
+            body.push(format!("    // {}번째 자격증명", i));
+            for (j, attribute) in self.opened_attributes(i).iter().enumerate() {
+                let param = format!("c{}_{}", i, attribute_param(attribute));
+                let visibility = if self.reveal[i].contains(attribute) {
+                    ""
+                } else {
+                    "private "
+                };
+                params.push(format!("{}field {}", visibility, param));
+                params.push(format!("private field[DEPTH][2] {}_path", param));
+
+                let index = leaf_index(attribute)?;
+                let directions = (0..DEPTH)
+                    .map(|level| ((index >> level) & 1 == 1).to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let root = if j == 0 {
+                    format!("c{}_root", i)
+                } else {
+                    format!("{}_root", param)
+                };
+                body.push(format!(
+                    "    field[2] {} = merkleRoot({}, [{}], {}, {}_path);",
+                    root, index, directions, param, param
+                ));
+                if j > 0 {
+                    body.push(format!(
+                        "    assert({root}[0] == c{i}_root[0] && {root}[1] == c{i}_root[1]);",
+                        root = root,
+                        i = i
+                    ));
+                }
+            }
+            body.push(format!(
+                "    u32[8] c{i}_M0 = unpack256u(c{i}_root[0]);",
+                i = i
+            ));
+            body.push(format!(
+                "    u32[8] c{i}_M1 = unpack256u(c{i}_root[1]);",
+                i = i
+            ));
+            body.push(format!(
+                "    bool c{i}_signature_valid = verifyEddsa(R[{i}], S[{i}], issuer_keys[{i}], c{i}_M0, c{i}_M1, BABYJUBJUB_PARAMS);",
+                i = i
+            ));
+            body.push(format!("    assert(c{}_signature_valid == true);", i));
+            body.push(format!("    assert(c{}_exp > current_time);", i));
+            body.push(String::new());
+        }
+
+        if !self.equal.is_empty() {
+            body.push("    // 자격증명 사이의 제약: 모든 자격증명에서 같은 속성 값".to_owned());
+            for attribute in &self.equal {
+                let param = attribute_param(attribute);
+                for i in 1..count {
+                    body.push(format!("    assert(c{}_{} == c0_{});", i, param, param));
+                }
+            }
+            body.push(String::new());
+        }
+
+        let mut holder_import = String::new();
+        if self.holder_binding {
+            params.push("private field holder_secret".to_owned());
+            holder_import = "from \"../../holder\" import holderCommitment;\n".to_owned();
+            body.push(
+                "    // 하나의 홀더 비밀값이 모든 자격증명의 홀더 커밋먼트와 맞는지 검사"
+                    .to_owned(),
+            );
+            body.push("    field commitment = holderCommitment(holder_secret);".to_owned());
+            for i in 0..count {
+                body.push(format!(
+                    "    assert(commitment == c{}_holder_commitment);",
+                    i
+                ));
+            }
+            body.push(String::new());
+        }
+        params.push(format!("private field[{}][2] R", count));
+        params.push(format!("private field[{}] S", count));
+
+        Ok(format!(
+            r#"import "utils/pack/u32/nonStrictUnpack256" as unpack256u;
+from "../../eddsa" import BABYJUBJUB_PARAMS, verifyEddsa;
+from "../../merkle" import DEPTH, merkleRoot;
+{holder_import}
+// 이 파일은 circuit.rs에서 생성됩니다.
+def main({params}) {{
+{body}
+    return;
+}}
+"#,
+            holder_import = holder_import,
+            params = params.join(", "),
+            body = body.join("\n"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_reveal_test() {
+        let reveal = vec!["id".to_owned(), "department".to_owned(), "id".to_owned()];
+        assert_eq!(normalize_reveal(&reveal).unwrap(), vec!["alumni_of", "id"]);
+        assert!(normalize_reveal(&["grade".to_owned()]).is_err());
+        assert_eq!(
+            CircuitSpec::new(&["context".to_owned(), "id".to_owned()], false)
+                .unwrap()
+                .dir("./zok/prover"),
+            PathBuf::from("./zok/prover/reveal_context_id")
+        );
+        assert_eq!(
+            CircuitSpec::new(&[], true).unwrap().dir("./zok/prover"),
+            PathBuf::from("./zok/prover/birth_date")
+        );
+    }
+
+    #[test]
+    fn render_test() {
+        let source = CircuitSpec::new(&["alumni_of".to_owned()], false)
+            .unwrap()
+            .render()
+            .unwrap();
+        assert!(source.contains(
+            "def main(field min_age, field current_time, private field age, private field[DEPTH][2] age_path, field alumni_of_hash, private field[DEPTH][2] alumni_of_hash_path, private field exp, private field[DEPTH][2] exp_path, private field[2] R, private field S)"
+        ));
+        assert!(source.contains("merkleRoot(1, [true, false, false, false], age, age_path)"));
+        assert!(source.contains(
+            "merkleRoot(2, [false, true, false, false], alumni_of_hash, alumni_of_hash_path)"
+        ));
+    }
+
+    #[test]
+    fn render_revocation_test() {
+        let spec = CircuitSpec {
+            revocation: true,
+            ..CircuitSpec::new(&["id".to_owned()], false).unwrap()
+        };
+        assert_eq!(
+            spec.public_inputs(),
+            vec![
+                "min_age",
+                "current_time",
+                "revocation_root[0]",
+                "revocation_root[1]",
+                "id"
+            ]
+        );
+        assert_eq!(
+            spec.dir("./zok/prover"),
+            PathBuf::from("./zok/prover/revocation_reveal_id")
+        );
+        let source = spec.render().unwrap();
+        assert!(source
+            .contains("def main(field min_age, field current_time, field[2] revocation_root, "));
+        assert!(source.contains("private field revocation_index, private field[DEPTH][2] revocation_index_path, private field[REVOCATION_DEPTH][2] revocation_path, private field[2] R"));
+        assert!(source.contains(
+            "from \"../../merkle\" import DEPTH, merkleRoot, REVOCATION_DEPTH, nonRevokedRoot;"
+        ));
+    }
+
+    #[test]
+    fn render_issuer_set_test() {
+        let spec = CircuitSpec {
+            revocation: true,
+            issuer_set: true,
+            ..CircuitSpec::default()
+        };
+        assert_eq!(
+            spec.public_inputs(),
+            vec![
+                "min_age",
+                "current_time",
+                "revocation_root[0]",
+                "revocation_root[1]",
+                "issuer_root[0]",
+                "issuer_root[1]"
+            ]
+        );
+        assert_eq!(
+            spec.dir("./zok/prover"),
+            PathBuf::from("./zok/prover/revocation_issuer_set")
+        );
+        let source = spec.render().unwrap();
+        assert!(source.contains("private field[REVOCATION_DEPTH][2] revocation_path, private field[2] issuer_key, private field issuer_index, private field[ISSUER_SET_DEPTH][2] issuer_path, private field[2] R"));
+        assert!(source.contains("from \"../../eddsa\" import BABYJUBJUB_PARAMS, verifyEddsa;"));
+        assert!(source.contains("verifyEddsa(R, S, issuer_key, M0, M1, BABYJUBJUB_PARAMS)"));
+        assert!(!CircuitSpec::default()
+            .render()
+            .unwrap()
+            .contains("issuer_key"));
+    }
+
+    #[test]
+    fn render_holder_binding_test() {
+        let spec = CircuitSpec {
+            holder_binding: true,
+            ..CircuitSpec::default()
+        };
+        assert_eq!(
+            spec.dir("./zok/prover"),
+            PathBuf::from("./zok/prover/holder")
+        );
+        let source = spec.render().unwrap();
+        assert!(source.contains("private field holder_commitment, private field[DEPTH][2] holder_commitment_path, private field holder_secret, private field[2] R"));
+        assert!(source.contains("from \"../../holder\" import holderCommitment;\n"));
+        assert!(source.contains("assert(holderCommitment(holder_secret) == holder_commitment);"));
+    }
+
+    #[test]
+    fn render_challenge_test() {
+        let spec = CircuitSpec {
+            holder_binding: true,
+            challenge: true,
+            ..CircuitSpec::default()
+        };
+        assert_eq!(
+            spec.public_inputs(),
+            vec![
+                "min_age",
+                "current_time",
+                "verifier_id",
+                "nonce",
+                "challenge_response"
+            ]
+        );
+        let source = spec.render().unwrap();
+        assert!(source.contains(
+            "def main(field min_age, field current_time, field verifier_id, field nonce, "
+        ));
+        assert!(source.contains("private field[2] R, private field S) -> field {"));
+        assert!(source.contains("    return challenge_response;\n}"));
+    }
+
+    #[test]
+    fn render_nullifier_test() {
+        let spec = CircuitSpec {
+            holder_binding: true,
+            challenge: true,
+            nullifier: true,
+            ..CircuitSpec::default()
+        };
+        assert_eq!(
+            spec.public_inputs(),
+            vec![
+                "min_age",
+                "current_time",
+                "verifier_id",
+                "nonce",
+                "scope",
+                "challenge_response",
+                "nullifier"
+            ]
+        );
+        let source = spec.render().unwrap();
+        assert!(source.contains(
+            "from \"../../holder\" import holderCommitment, challengeResponse, scopedNullifier;"
+        ));
+        assert!(source.contains(") -> (field, field) {"));
+        assert!(source.contains("    return (challenge_response, nullifier);\n}"));
+
+        let contract = spec.render_contract().unwrap();
+        assert!(contract.contains("uint[7] memory input"));
+        assert!(contract.contains("uint256 scope = input[4];"));
+        assert!(contract.contains("uint256 nullifier = input[6];"));
+
+        // 홀더 바인딩이 없는 널리파이어 회로, 널리파이어가 없는 래퍼 컨트랙트는 만들 수 없음
+        let unbound = CircuitSpec {
+            holder_binding: false,
+            ..spec
+        };
+        assert!(matches!(
+            unbound.render(),
+            Err(CredentialError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            CircuitSpec::default().render_contract(),
+            Err(CredentialError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn render_pseudonym_test() {
+        let spec = CircuitSpec {
+            holder_binding: true,
+            pseudonym: true,
+            ..CircuitSpec::new(&["id".to_owned()], false).unwrap()
+        };
+        assert_eq!(
+            spec.public_inputs(),
+            vec![
+                "min_age",
+                "current_time",
+                "verifier_domain",
+                "id",
+                "pseudonym"
+            ]
+        );
+        assert_eq!(
+            spec.dir("./zok/prover"),
+            PathBuf::from("./zok/prover/holder_pseudonym_reveal_id")
+        );
+        let source = spec.render().unwrap();
+        assert!(
+            source.contains("field pseudonym = verifierPseudonym(holder_secret, verifier_domain);")
+        );
+        assert!(source.contains("    return pseudonym;\n}"));
+    }
+
+    #[test]
+    fn render_multi_test() {
+        let spec = MultiCircuitSpec::new(
+            &[
+                vec!["type".to_owned()],
+                vec!["type".to_owned(), "name".to_owned()],
+            ],
+            &["id".to_owned()],
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            spec.public_inputs(),
+            vec![
+                "current_time",
+                "issuer_keys[0][0]",
+                "issuer_keys[0][1]",
+                "issuer_keys[1][0]",
+                "issuer_keys[1][1]",
+                "0.type",
+                "1.name",
+                "1.type"
+            ]
+        );
+        assert_eq!(
+            spec.dir("./zok/prover"),
+            PathBuf::from("./zok/prover/multi_2_holder_equal_id_0_reveal_type_1_reveal_name_type")
+        );
+        let source = spec.render().unwrap();
+        assert!(source.contains(
+            "def main(field current_time, field[2][2] issuer_keys, private field c0_exp, "
+        ));
+        assert!(source.contains("field c1_type_hash, private field[DEPTH][2] c1_type_hash_path, private field c1_holder_commitment, private field[DEPTH][2] c1_holder_commitment_path, private field holder_secret, private field[2][2] R, private field[2] S)"));
+        assert!(source
+            .contains("verifyEddsa(R[1], S[1], issuer_keys[1], c1_M0, c1_M1, BABYJUBJUB_PARAMS);"));
+        assert!(source.contains("assert(c1_id_hash == c0_id_hash);"));
+        assert!(source.contains("assert(commitment == c1_holder_commitment);"));
+    }
+
+    #[test]
+    fn render_birth_date_test() {
+        let spec = CircuitSpec::new(&[], true).unwrap();
+        assert_eq!(
+            spec.public_inputs(),
+            vec!["min_age", "current_time", "today"]
+        );
+        let source = spec.render().unwrap();
+        assert!(source.contains("def main(field min_age, field current_time, field today, private field exp, private field[DEPTH][2] exp_path, private field birth_date, private field[DEPTH][2] birth_date_path"));
+        assert!(source.contains("assert(4 * (today - birth_date) >= 1461 * min_age);"));
+        assert!(!source.contains("age >= min_age"));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::backend::{ZokratesCli, ZOKRATES};
+use crate::eddsa::ISSUER_PUBLIC_KEY;
+use crate::error::{create_dir, write_file, CredentialError, Result};
+use crate::proving_system::ProvingSystem;
+use num_bigint::BigUint;
+use std::path::{Path, PathBuf};
+
+// 생성한 회로(verify_credential.zok)가 "../../<이름>"으로 가져오는 라이브러리
+// 크레이트에 포함하므로 build.rs가 target 디렉토리에 복사한 파일이 없어도 됩니다.
+const LIBRARIES: [(&str, &str); 3] = [
+    ("eddsa.zok", include_str!("../zok/eddsa.zok")),
+    ("merkle.zok", include_str!("../zok/merkle.zok")),
+    ("holder.zok", include_str!("../zok/holder.zok")),
+];
+
+// 발급자, 프루버, 검증자(issuer::Issuer, prover::Prover, verifier::Verifier)의 설정
+// 현재 디렉토리 대신 root 아래에 CLI의 ./zok와 같은 구성으로 파일을 둡니다:
+// <root>/issuer (폐기 레지스트리), <root>/prover (컴파일한 회로), <root>/verifier (회로와 키)
+#[derive(Debug, Clone)]
+pub struct Config {
+    // 작업 루트
+    pub root: PathBuf,
+    // 검증자가 설정한 회로 구성별 디렉토리(proving.key, verification.key)의 상위 디렉토리
+    // 기본값은 <root>/verifier이며, 홀더는 검증자에게서 받은 키를 같은 구성으로 둔 디렉토리를 지정합니다.
+    pub keys_dir: PathBuf,
+    // zokrates 실행 파일 (기본값: $PATH의 zokrates)
+    pub zokrates: PathBuf,
+    // 설정과 증명에 사용하는 증명 방식, 백엔드, 곡선
+    pub proving_system: ProvingSystem,
+    // 발급자 공개키 ("A.x", "A.y")
+    // 발급자 집합을 쓰지 않는 회로는 zok/eddsa.zok의 ISSUER_PUBLIC_KEY로 서명을 검증하므로,
+    // issuer_set이 아니면 기본값과 다른 키는 Issuer와 Prover가 거절합니다.
+    pub issuer_public_key: [String; 2],
+    // 발급자 키를 고정하지 않는 발급자 집합 회로(CircuitSpec::issuer_set)로만 증명하는지 여부
+    pub issuer_set: bool,
+    // 발급자 비밀키 (Issuer만 사용, 공개키와 짝이어야 합니다.)
+    pub issuer_secret_key: Option<BigUint>,
+}
+
+impl Config {
+    pub fn new(root: impl Into<PathBuf>) -> Config {
+        let root = root.into();
+        Config {
+            keys_dir: root.join("verifier"),
+            root,
+            zokrates: PathBuf::from(ZOKRATES),
+            proving_system: ProvingSystem::default(),
+            issuer_public_key: ISSUER_PUBLIC_KEY.map(str::to_owned),
+            issuer_set: false,
+            issuer_secret_key: None,
+        }
+    }
+
+    pub fn issuer_dir(&self) -> PathBuf {
+        self.root.join("issuer")
+    }
+
+    pub fn prover_dir(&self) -> PathBuf {
+        self.root.join("prover")
+    }
+
+    // 설정의 zokrates 실행 파일과 증명 시스템을 쓰는 백엔드
+    pub fn backend(&self) -> ZokratesCli {
+        ZokratesCli::with_binary(self.proving_system.clone(), &self.zokrates)
+    }
+
+    // 발급자 키를 고정한 회로로 검증할 수 있는 키인지 확인 (다르면 증명이 항상 실패합니다.)
+    pub(crate) fn check_fixed_issuer_key(&self) -> Result<()> {
+        if self.issuer_public_key != ISSUER_PUBLIC_KEY {
+            return Err(CredentialError::InvalidInput(
+                "Only eddsa::ISSUER_PUBLIC_KEY can be verified without an issuer set".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+// 회로 구성별 디렉토리들의 상위 디렉토리(base_dir)를 만들고 그 부모에 회로 라이브러리를 씁니다.
+pub(crate) fn install_libraries(base_dir: &Path) -> Result<()> {
+    create_dir(base_dir)?;
+    for (name, source) in LIBRARIES {
+        write_file(base_dir.join("..").join(name), source)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs};
+
+    #[test]
+    fn install_libraries_test() {
+        let config = Config::new(env::temp_dir().join("install_libraries_test"));
+        install_libraries(&config.keys_dir).unwrap();
+        for (name, source) in LIBRARIES {
+            assert_eq!(fs::read_to_string(config.root.join(name)).unwrap(), source);
+        }
+        assert!(config.keys_dir.is_dir());
+        assert_eq!(config.prover_dir(), config.root.join("prover"));
+        fs::remove_dir_all(&config.root).unwrap();
+    }
+}
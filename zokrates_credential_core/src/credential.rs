@@ -1,11 +1,7 @@
-use chrono::{DateTime, Utc};
+use crate::error::{load_json, CredentialError, Result};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct Credential {
-    pub claim: Claims,
-}
-
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Claims {
     // 필드명을 스네이크 케이스로 변경
@@ -18,6 +14,10 @@ pub struct Claims {
     pub issuance_date: DateTime<Utc>,
     pub credential_subject: CredentialSubject,
     pub exp: i64,
+    // 폐기 레지스트리에서의 위치 (발급자가 부여)
+    pub revocation_index: u64,
+    // 상태 목록(status_list.rs)에서 이 자격증명의 위치
+    pub credential_status: CredentialStatus,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -31,8 +31,14 @@ pub struct CredentialSubject {
     pub id: String,
     pub name: String,
     pub age: u8,
+    // 생년월일: DAY_EPOCH(1900-01-01)부터의 일 수 (회로에서 비교할 수 있도록 정수로 저장)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birth_date: Option<i64>,
     pub student_number: String,
     pub alumni_of: AlumniOf,
+    // 홀더 비밀값에 대한 커밋먼트 (10진수, holder.rs 참고)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holder_commitment: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -41,3 +47,99 @@ pub struct AlumniOf {
     pub name: String,
     pub department: String,
 }
+
+// W3C Bitstring Status List 항목 (status_list_index는 폐기 인덱스와 같습니다.)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CredentialStatus {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub status_type: String,
+    pub status_purpose: String,
+    pub status_list_index: String,
+    pub status_list_credential: String,
+}
+
+// 일 수의 기준 날짜 (회로의 속성 값은 음수가 될 수 없으므로 모든 생년월일보다 앞선 날짜)
+pub const DAY_EPOCH: NaiveDate = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap();
+
+// 날짜를 DAY_EPOCH부터의 일 수로 변환 (DAY_EPOCH 이전의 날짜는 음수)
+pub fn day_number(date: NaiveDate) -> i64 {
+    date.signed_duration_since(DAY_EPOCH).num_days()
+}
+
+// 머클 트리 리프가 되는 속성 키 (배열 순서가 리프 위치)
+// 새 속성은 뒤에 추가하면 되며, 최대 merkle::LEAF_COUNT개까지 담을 수 있습니다.
+// 값이 없는 선택 속성(birth_date 등)의 리프는 빈 노드로 남습니다.
+pub const ATTRIBUTES: [&str; 16] = [
+    "@context",
+    "age",
+    "alumni_of",
+    "credential_subject",
+    "exp",
+    "id",
+    "issuance_date",
+    "issuer",
+    "name",
+    "student_number",
+    "type",
+    "birth_date",
+    "revocation_index",
+    "credential_status",
+    "holder_commitment",
+    "subject_id",
+];
+
+// 속성의 리프 위치
+pub fn leaf_index(attribute: &str) -> Result<usize> {
+    ATTRIBUTES
+        .iter()
+        .position(|&a| a == attribute)
+        .ok_or_else(|| CredentialError::InvalidInput(format!("Unknown attribute: {}", attribute)))
+}
+
+// 발급된 자격증명: 원본 클레임과 머클 트리 리프가 되는 속성 커밋먼트
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Credential {
+    pub claim: Claims,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Attribute {
+    pub name: String,
+    // 정수 속성은 값 그대로, 나머지는 해시를 38자리 10진수 문자열로 표현
+    pub value: String,
+}
+
+impl Credential {
+    pub fn load(credential_path: &str) -> Result<Credential> {
+        load_json("credential", credential_path)
+    }
+
+    // 속성 값 (자격증명에 없는 속성이면 None)
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    // 리프 위치별 속성 값 (값이 없는 리프는 None)
+    pub fn leaves(&self) -> Result<Vec<Option<u128>>> {
+        ATTRIBUTES
+            .iter()
+            .map(|name| {
+                self.value(name)
+                    .map(|value| {
+                        value.parse().map_err(|_| {
+                            CredentialError::parse(
+                                "credential",
+                                format!("{} must be a decimal number", name),
+                            )
+                        })
+                    })
+                    .transpose()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,167 @@
+use crate::eddsa::{self, Point, ISSUER_PUBLIC_KEY};
+use ark_ed_on_bn254::Fq;
+use ark_ff::{Field, One, PrimeField};
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+
+// BabyJubJub 공개키의 multicodec 코드
+// 등록된 코드가 없으므로 multicodec의 사설 영역(0x300000 ~ 0x3fffff)의 값을 사용합니다.
+pub const BABYJUBJUB_PUB_MULTICODEC: u64 = 0x300000;
+
+const DID_KEY_PREFIX: &str = "did:key:";
+
+// unsigned varint (LEB128) 인코딩
+fn encode_varint(mut value: u64) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn decode_varint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+    for (i, &byte) in bytes.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, &bytes[i + 1..]));
+        }
+    }
+    None
+}
+
+// 압축한 공개키 (32바이트): y를 리틀엔디언으로 쓰고, x가 (p - 1) / 2보다 크면 최상위 비트를 켭니다.
+// circomlib의 packPoint와 같은 형식입니다.
+pub fn compress(public_key: &Point) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    let y = BigUint::from(public_key[1]).to_bytes_le();
+    bytes[..y.len()].copy_from_slice(&y);
+    let half = BigUint::from(Fq::MODULUS) >> 1;
+    if BigUint::from(public_key[0]) > half {
+        bytes[31] |= 0x80;
+    }
+    bytes
+}
+
+// 압축한 공개키 복원: x^2 = (1 - y^2) / (a - d * y^2)
+pub fn decompress(bytes: &[u8; 32]) -> Option<Point> {
+    let mut y_bytes = *bytes;
+    let sign = y_bytes[31] & 0x80 != 0;
+    y_bytes[31] &= 0x7f;
+    let y = BigUint::from_bytes_le(&y_bytes);
+    if y >= BigUint::from(Fq::MODULUS) {
+        return None;
+    }
+    let y = Fq::from(y);
+    let yy = y.square();
+    let xx = (Fq::one() - yy)
+        * (Fq::from(eddsa::JUBJUB_A) - Fq::from(eddsa::JUBJUB_D) * yy).inverse()?;
+    let mut x = xx.sqrt()?;
+    let half = BigUint::from(Fq::MODULUS) >> 1;
+    if (BigUint::from(x) > half) != sign {
+        x = -x;
+    }
+    Some([x, y])
+}
+
+// 공개키의 did:key ("did:key:z" + base58btc(multicodec varint || 압축한 공개키))
+pub fn did_key(public_key: &Point) -> String {
+    let mut bytes = encode_varint(BABYJUBJUB_PUB_MULTICODEC);
+    bytes.extend(compress(public_key));
+    format!("{}z{}", DID_KEY_PREFIX, bs58::encode(bytes).into_string())
+}
+
+// did:key에서 BabyJubJub 공개키 복원 (다른 형식이거나 곡선 위의 점이 아니면 None)
+pub fn public_key(did: &str) -> Option<Point> {
+    let encoded = did.strip_prefix(DID_KEY_PREFIX)?.strip_prefix('z')?;
+    let bytes = bs58::decode(encoded).into_vec().ok()?;
+    let (codec, key) = decode_varint(&bytes)?;
+    if codec != BABYJUBJUB_PUB_MULTICODEC {
+        return None;
+    }
+    decompress(&<[u8; 32]>::try_from(key).ok()?)
+}
+
+// 발급자의 DID (자격증명의 issuer.id)
+pub fn issuer_did() -> String {
+    did_key(&eddsa::constant_point(ISSUER_PUBLIC_KEY))
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub method_type: String,
+    pub controller: String,
+    pub public_key_multibase: String,
+}
+
+// did:key를 해석한 DID 문서: 공개키 하나를 검증 방법으로 가집니다.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    pub verification_method: Vec<VerificationMethod>,
+    pub authentication: Vec<String>,
+    pub assertion_method: Vec<String>,
+}
+
+// did:key 해석 (BabyJubJub 공개키가 아니면 None)
+pub fn resolve(did: &str) -> Option<DidDocument> {
+    public_key(did)?;
+    let multibase = did.strip_prefix(DID_KEY_PREFIX)?;
+    let method_id = format!("{}#{}", did, multibase);
+    Some(DidDocument {
+        context: vec![
+            "https://www.w3.org/ns/did/v1".to_owned(),
+            "https://w3id.org/security/multikey/v1".to_owned(),
+        ],
+        id: did.to_owned(),
+        verification_method: vec![VerificationMethod {
+            id: method_id.clone(),
+            method_type: "Multikey".to_owned(),
+            controller: did.to_owned(),
+            public_key_multibase: multibase.to_owned(),
+        }],
+        authentication: vec![method_id.clone()],
+        assertion_method: vec![method_id],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn did_key_test() {
+        let issuer = eddsa::constant_point(ISSUER_PUBLIC_KEY);
+        let did = did_key(&issuer);
+        assert!(did.starts_with("did:key:z"));
+        assert_eq!(public_key(&did), Some(issuer));
+        assert_eq!(
+            decompress(&compress(&[-issuer[0], issuer[1]])),
+            Some([-issuer[0], issuer[1]])
+        );
+
+        let document = resolve(&did).unwrap();
+        assert_eq!(document.id, did);
+        assert_eq!(
+            document.assertion_method,
+            vec![document.verification_method[0].id.clone()]
+        );
+        assert_eq!(
+            document.verification_method[0].public_key_multibase,
+            did.strip_prefix("did:key:").unwrap()
+        );
+        assert!(resolve("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK").is_none());
+        assert!(resolve("did:example:abcdef1234567890").is_none());
+    }
+}
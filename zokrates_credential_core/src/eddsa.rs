@@ -0,0 +1,221 @@
+use crate::error::{read_file, CredentialError, Result};
+use ark_ed_on_bn254::Fq;
+use ark_ff::{Field, One, PrimeField, Zero};
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+use std::{fmt, str::FromStr};
+
+// zok/eddsa.zok의 BABYJUBJUB_PARAMS와 같은 곡선: a * x^2 + y^2 = 1 + d * x^2 * y^2
+pub const JUBJUB_A: u64 = 168700;
+pub const JUBJUB_D: u64 = 168696;
+pub(crate) const GENERATOR: [&str; 2] = [
+    "16540640123574156134436876038791482806971768689494387082833631921987005038935",
+    "20819045374670962167435360035096875258406992893633759881276124905556507972311",
+];
+
+// zok/eddsa.zok의 ISSUER_PUBLIC_KEY와 같아야 합니다.
+pub const ISSUER_PUBLIC_KEY: [&str; 2] = [
+    "14897476871502190904409029696666322856887678969656209656241038339251270171395",
+    "16668832459046858928951622951481252834155254151733002984053501254009901876174",
+];
+
+pub type Point = [Fq; 2];
+
+// 10진수 좌표 [x, y]의 점 (필드 모듈러스 미만의 값이고 곡선 위에 있어야 합니다.)
+pub fn point(coordinates: [&str; 2]) -> Result<Point> {
+    let parse = |value: &str| {
+        BigUint::from_str(value)
+            .ok()
+            .filter(|parsed| parsed < &BigUint::from(Fq::MODULUS))
+            .map(Fq::from)
+            .ok_or_else(|| {
+                CredentialError::InvalidInput(format!("Invalid field element: {}", value))
+            })
+    };
+    let p = [parse(coordinates[0])?, parse(coordinates[1])?];
+    if !is_on_curve(&p) {
+        return Err(CredentialError::InvalidInput(format!(
+            "Not a point on the curve: [{}, {}]",
+            coordinates[0], coordinates[1]
+        )));
+    }
+    Ok(p)
+}
+
+// GENERATOR, ISSUER_PUBLIC_KEY처럼 곡선 위에 있는 상수 좌표의 점
+pub(crate) fn constant_point(coordinates: [&str; 2]) -> Point {
+    point(coordinates).expect("Constant is not a point on the curve")
+}
+
+// 발급자 공개키 파일(issuer_public_key)의 "A.x A.y" (10진수)
+pub fn load_public_key(key_path: &str) -> Result<[String; 2]> {
+    let data = read_file(key_path)?;
+    let key = <[String; 2]>::try_from(
+        data.split_whitespace()
+            .map(str::to_owned)
+            .collect::<Vec<_>>(),
+    )
+    .map_err(|_| CredentialError::parse("issuer public key", "expected two decimal numbers"))?;
+    point([&key[0], &key[1]])
+        .map_err(|_| CredentialError::parse("issuer public key", "not a point on the curve"))?;
+    Ok(key)
+}
+
+// 비밀키에 대응하는 공개키 (sk * G)
+pub fn derive_public_key(secret_key: &BigUint) -> Point {
+    mul(&constant_point(GENERATOR), secret_key)
+}
+
+fn is_on_curve(p: &Point) -> bool {
+    let (xx, yy) = (p[0].square(), p[1].square());
+    Fq::from(JUBJUB_A) * xx + yy == Fq::one() + Fq::from(JUBJUB_D) * xx * yy
+}
+
+// 트위스티드 에드워즈 덧셈 (d가 비이차잉여이므로 곡선 위의 모든 점에 대해 정의됩니다.)
+pub(crate) fn add(p: &Point, q: &Point) -> Point {
+    let t = Fq::from(JUBJUB_D) * p[0] * q[0] * p[1] * q[1];
+    let x = (p[0] * q[1] + p[1] * q[0]) * (Fq::one() + t).inverse().unwrap();
+    let y = (p[1] * q[1] - Fq::from(JUBJUB_A) * p[0] * q[0]) * (Fq::one() - t).inverse().unwrap();
+    [x, y]
+}
+
+pub(crate) fn mul(p: &Point, scalar: &BigUint) -> Point {
+    let mut result = [Fq::zero(), Fq::one()];
+    for i in (0..scalar.bits()).rev() {
+        result = add(&result, &result);
+        if scalar.bit(i) {
+            result = add(&result, p);
+        }
+    }
+    result
+}
+
+// 서명 파일(create_signature.py 출력)의 "R.x R.y S"
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature {
+    pub r: Point,
+    pub s: BigUint,
+}
+
+impl Signature {
+    pub fn parse(text: &str) -> Option<Signature> {
+        let values = text.split_whitespace().collect::<Vec<_>>();
+        let [rx, ry, s] = values[..] else {
+            return None;
+        };
+        Some(Signature {
+            r: [Fq::from_str(rx).ok()?, Fq::from_str(ry).ok()?],
+            s: BigUint::from_str(s).ok()?,
+        })
+    }
+}
+
+// Signature::parse가 읽는 "R.x R.y S" (10진수)
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            BigUint::from(self.r[0]),
+            BigUint::from(self.r[1]),
+            self.s
+        )
+    }
+}
+
+// hRAM = sha256(R.x || A.x || M): zokrates_pycrypto, zok/eddsa.zok의 verifyEddsa와 같은 해시
+fn challenge(r: &Point, public_key: &Point, message: &[u32; 16]) -> BigUint {
+    let mut hasher = Sha256::new();
+    for coordinate in [r[0], public_key[0]] {
+        let bytes = BigUint::from(coordinate).to_bytes_be();
+        hasher.update(vec![0u8; 32 - bytes.len()]);
+        hasher.update(bytes);
+    }
+    for word in message {
+        hasher.update(word.to_be_bytes());
+    }
+    BigUint::from_bytes_be(&hasher.finalize())
+}
+
+// 회로 밖에서 발급자 서명 검증 (S * G == R + hRAM * A)
+// message: 회로의 M0, M1과 같은 16개의 u32 (merkle::message_words 참고)
+pub fn verify(public_key: &Point, signature: &Signature, message: &[u32; 16]) -> bool {
+    if !is_on_curve(&signature.r) || !is_on_curve(public_key) {
+        return false;
+    }
+    let h = challenge(&signature.r, public_key, message);
+    mul(&constant_point(GENERATOR), &signature.s) == add(&signature.r, &mul(public_key, &h))
+}
+
+// create_signature.py의 디버그용 고정 비밀키
+#[cfg(test)]
+pub(crate) const ISSUER_SECRET_KEY: &str =
+    "1997011358982923168928344992199991480689546837621580239342656433234255379025";
+// 곡선의 위수 (S는 이 값으로 나눈 나머지)
+const JUBJUB_E: &str =
+    "21888242871839275222246405745257275088614511777268538073601725287587578984328";
+
+// zokrates_pycrypto PrivateKey.sign과 같은 방식의 서명
+// 같은 키와 메시지이면 create_signature.py와 같은 서명을 만듭니다.
+pub fn sign(secret_key: &BigUint, message: &[u32; 16]) -> Signature {
+    let mut hasher = Sha256::new();
+    let bytes = secret_key.to_bytes_be();
+    hasher.update(vec![0u8; 32 - bytes.len()]);
+    hasher.update(bytes);
+    for word in message {
+        hasher.update(word.to_be_bytes());
+    }
+    let r = BigUint::from_bytes_be(&hasher.finalize());
+    let big_r = mul(&constant_point(GENERATOR), &r);
+    let public_key = mul(&constant_point(GENERATOR), secret_key);
+    let h = challenge(&big_r, &public_key, message);
+    Signature {
+        r: big_r,
+        s: (r + secret_key * h) % BigUint::from_str(JUBJUB_E).unwrap(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn public_key_test() {
+        let secret_key = BigUint::from_str(ISSUER_SECRET_KEY).unwrap();
+        assert_eq!(
+            mul(&constant_point(GENERATOR), &secret_key),
+            constant_point(ISSUER_PUBLIC_KEY)
+        );
+    }
+
+    #[test]
+    fn verify_test() {
+        let secret_key = BigUint::from_str(ISSUER_SECRET_KEY).unwrap();
+        let message = [7u32; 16];
+        let signature = sign(&secret_key, &message);
+        let text = signature.to_string();
+        assert_eq!(Signature::parse(&text), Some(signature.clone()));
+
+        let public_key = constant_point(ISSUER_PUBLIC_KEY);
+        assert!(verify(&public_key, &signature, &message));
+        assert!(!verify(&public_key, &signature, &[8u32; 16]));
+    }
+
+    // 모듈러스 이상의 좌표, 곡선 밖의 점, 숫자가 아닌 좌표는 패닉 없이 거부
+    #[test]
+    fn point_test() {
+        let x = BigUint::from_str(ISSUER_PUBLIC_KEY[0]).unwrap();
+        let congruent = (x + BigUint::from(Fq::MODULUS)).to_string();
+        for coordinates in [
+            [congruent.as_str(), ISSUER_PUBLIC_KEY[1]],
+            ["1", "1"],
+            ["x", ISSUER_PUBLIC_KEY[1]],
+        ] {
+            assert!(matches!(
+                point(coordinates),
+                Err(CredentialError::InvalidInput(_))
+            ));
+        }
+        assert!(point(ISSUER_PUBLIC_KEY).is_ok());
+    }
+}
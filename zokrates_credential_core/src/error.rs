@@ -0,0 +1,146 @@
+use serde::{de::DeserializeOwned, Serialize};
+use std::{error, fmt, fs, io, path::Path};
+
+// 코어 크레이트의 공개 함수가 반환하는 오류
+#[derive(Debug)]
+pub enum CredentialError {
+    // 파일을 읽거나 쓰지 못함
+    Io { path: String, source: io::Error },
+    // zokrates 명령어가 실패함 (표준 에러 출력 포함)
+    Zokrates { command: String, stderr: String },
+    // 파일이나 값의 형식이 잘못됨 (JSON, 키, 서명, 증명 등)
+    Parse { what: String, message: String },
+    // 서명이 발급자 키로 검증되지 않음
+    InvalidSignature(String),
+    // 자격증명이 증명할 조건을 만족하지 않음 (폐기, 홀더 비밀값, 신뢰하는 발급자 등)
+    UnsatisfiedPredicate(String),
+    // 먼저 설정해야 하는 회로, 키, SRS 등이 없음
+    MissingSetup(String),
+    // 잘못된 인자나 구성 (빠진 옵션, 맞지 않는 증명 시스템 등)
+    InvalidInput(String),
+}
+
+pub type Result<T> = std::result::Result<T, CredentialError>;
+
+impl fmt::Display for CredentialError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CredentialError::Io { path, source } => write!(f, "{}: {}", path, source),
+            CredentialError::Zokrates { command, stderr } => {
+                write!(f, "zokrates {} failed", command)?;
+                if !stderr.trim().is_empty() {
+                    write!(f, ": {}", stderr.trim())?;
+                }
+                Ok(())
+            }
+            CredentialError::Parse { what, message } => {
+                write!(f, "Invalid {}: {}", what, message)
+            }
+            CredentialError::InvalidSignature(message)
+            | CredentialError::UnsatisfiedPredicate(message)
+            | CredentialError::MissingSetup(message)
+            | CredentialError::InvalidInput(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl error::Error for CredentialError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CredentialError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl CredentialError {
+    pub(crate) fn io(path: impl AsRef<Path>, source: io::Error) -> CredentialError {
+        CredentialError::Io {
+            path: path.as_ref().display().to_string(),
+            source,
+        }
+    }
+
+    pub(crate) fn parse(what: &str, message: impl fmt::Display) -> CredentialError {
+        CredentialError::Parse {
+            what: what.to_owned(),
+            message: message.to_string(),
+        }
+    }
+}
+
+pub(crate) fn read_file(path: impl AsRef<Path>) -> Result<String> {
+    fs::read_to_string(&path).map_err(|error| CredentialError::io(path, error))
+}
+
+pub(crate) fn write_file(path: impl AsRef<Path>, data: impl AsRef<[u8]>) -> Result<()> {
+    fs::write(&path, data).map_err(|error| CredentialError::io(path, error))
+}
+
+pub(crate) fn copy_file(source: impl AsRef<Path>, destination: impl AsRef<Path>) -> Result<()> {
+    match fs::copy(&source, &destination) {
+        Ok(_) => Ok(()),
+        // 설정이 만든 파일이 없으면 설정부터 해야 합니다.
+        Err(error) if error.kind() == io::ErrorKind::NotFound && !source.as_ref().exists() => {
+            Err(CredentialError::MissingSetup(format!(
+                "{} not found. Setup first",
+                source.as_ref().display()
+            )))
+        }
+        Err(error) => Err(CredentialError::io(destination, error)),
+    }
+}
+
+pub(crate) fn create_dir(path: impl AsRef<Path>) -> Result<()> {
+    fs::create_dir_all(&path).map_err(|error| CredentialError::io(path, error))
+}
+
+// what: 오류 메시지에 쓸 파일의 종류 (예: "credential")
+pub(crate) fn load_json<T: DeserializeOwned>(what: &str, path: impl AsRef<Path>) -> Result<T> {
+    serde_json::from_str(&read_file(path)?).map_err(|error| CredentialError::parse(what, error))
+}
+
+pub(crate) fn save_json<T: Serialize>(path: impl AsRef<Path>, value: &T) -> Result<()> {
+    let data = serde_json::to_string_pretty(value).map_err(|error| {
+        CredentialError::InvalidInput(format!("Failed to serialize JSON: {}", error))
+    })?;
+    write_file(path, data)
+}
+
+// zokrates 인자나 파일 경로 문자열로 쓸 경로 (UTF-8이 아니면 InvalidInput)
+pub(crate) fn path_str(path: &Path) -> Result<String> {
+    path.to_str().map(str::to_owned).ok_or_else(|| {
+        CredentialError::InvalidInput(format!("Path is not valid UTF-8: {}", path.display()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_test() {
+        let missing = std::env::temp_dir().join("error_test_missing.json");
+        let error = load_json::<Vec<String>>("proof", &missing).unwrap_err();
+        assert!(matches!(error, CredentialError::Io { .. }));
+        assert!(error.to_string().contains("error_test_missing.json"));
+
+        let path = std::env::temp_dir().join("error_test.json");
+        fs::write(&path, "{").unwrap();
+        let error = load_json::<Vec<String>>("proof", &path).unwrap_err();
+        assert!(error.to_string().starts_with("Invalid proof: "));
+        fs::remove_file(&path).unwrap();
+
+        let error = copy_file(&missing, &path).unwrap_err();
+        assert!(matches!(error, CredentialError::MissingSetup(_)));
+
+        let error = CredentialError::Zokrates {
+            command: "compute-witness".to_owned(),
+            stderr: "Execution failed: Assertion failed\n".to_owned(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "zokrates compute-witness failed: Execution failed: Assertion failed"
+        );
+    }
+}
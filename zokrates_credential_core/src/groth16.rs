@@ -0,0 +1,312 @@
+use crate::error::{load_json, CredentialError, Result};
+use crate::proof::Proof;
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::{Proof as Groth16Proof, VerifyingKey};
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+// ZoKrates가 JSON에 쓰는 곡선 위의 점 (16진수 문자열)
+// G1은 [x, y], G2는 [[x.c1, x.c0], [y.c1, y.c0]]로 verifier.sol(EIP-197)과 같이 허수부가 먼저 옵니다.
+pub type G1 = [String; 2];
+pub type G2 = [[String; 2]; 2];
+
+// `zokrates setup`이 생성하는 verification.key 형식 (g16, bn128)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VerificationKey {
+    pub scheme: String,
+    pub curve: String,
+    pub alpha: G1,
+    pub beta: G2,
+    pub gamma: G2,
+    pub delta: G2,
+    pub gamma_abc: Vec<G1>,
+}
+
+impl VerificationKey {
+    pub fn load(key_path: &str) -> Result<VerificationKey> {
+        load_json("verification key", key_path)
+    }
+}
+
+// proof.json의 "proof" 항목 (g16)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProofPoints {
+    pub a: G1,
+    pub b: G2,
+    pub c: G1,
+}
+
+// scheme과 curve가 이 검증기가 지원하는 g16, bn128인지 확인
+pub fn check_scheme(scheme: &str, curve: &str) -> Result<()> {
+    if scheme != "g16" {
+        return Err(CredentialError::InvalidInput(format!(
+            "Unsupported proving scheme: {}",
+            scheme
+        )));
+    }
+    if curve != "bn128" {
+        return Err(CredentialError::InvalidInput(format!(
+            "Unsupported curve: {}",
+            curve
+        )));
+    }
+    Ok(())
+}
+
+// "0x..." 16진수를 필드 원소로 변환 (모듈러스 이상이면 오류)
+fn parse_hex<F: PrimeField>(hex: &str) -> Result<F> {
+    let value = hex
+        .strip_prefix("0x")
+        .and_then(|digits| BigUint::parse_bytes(digits.as_bytes(), 16))
+        .ok_or_else(|| CredentialError::parse("field element", hex))?;
+    if value >= F::MODULUS.into() {
+        return Err(CredentialError::parse(
+            "field element",
+            format!("{} is out of range", hex),
+        ));
+    }
+    Ok(F::from_be_bytes_mod_order(&value.to_bytes_be()))
+}
+
+pub fn parse_fr(hex: &str) -> Result<Fr> {
+    parse_hex(hex)
+}
+
+pub fn parse_g1(point: &G1) -> Result<G1Affine> {
+    let point = G1Affine::new_unchecked(parse_hex::<Fq>(&point[0])?, parse_hex::<Fq>(&point[1])?);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(CredentialError::parse("G1 point", "not on the curve"));
+    }
+    Ok(point)
+}
+
+pub fn parse_g2(point: &G2) -> Result<G2Affine> {
+    let fq2 = |pair: &[String; 2]| -> Result<Fq2> {
+        Ok(Fq2::new(parse_hex(&pair[1])?, parse_hex(&pair[0])?))
+    };
+    let point = G2Affine::new_unchecked(fq2(&point[0])?, fq2(&point[1])?);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(CredentialError::parse("G2 point", "not on the curve"));
+    }
+    Ok(point)
+}
+
+pub fn parse_verification_key(key: &VerificationKey) -> Result<VerifyingKey<Bn254>> {
+    check_scheme(&key.scheme, &key.curve)?;
+    Ok(VerifyingKey {
+        alpha_g1: parse_g1(&key.alpha)?,
+        beta_g2: parse_g2(&key.beta)?,
+        gamma_g2: parse_g2(&key.gamma)?,
+        delta_g2: parse_g2(&key.delta)?,
+        gamma_abc_g1: key.gamma_abc.iter().map(parse_g1).collect::<Result<_>>()?,
+    })
+}
+
+pub fn parse_proof(proof: &Value) -> Result<Groth16Proof<Bn254>> {
+    let points: ProofPoints = serde_json::from_value(proof.clone())
+        .map_err(|error| CredentialError::parse("g16 proof", error))?;
+    Ok(Groth16Proof {
+        a: parse_g1(&points.a)?,
+        b: parse_g2(&points.b)?,
+        c: parse_g1(&points.c)?,
+    })
+}
+
+// 필드 원소를 ZoKrates와 같은 "0x" + 64자리 16진수로 변환
+pub fn encode_hex<F: PrimeField>(value: F) -> String {
+    let bytes = value.into_bigint().to_bytes_be();
+    format!("0x{:0>64}", BigUint::from_bytes_be(&bytes).to_str_radix(16))
+}
+
+pub fn encode_g1(point: &G1Affine) -> G1 {
+    [encode_hex(point.x), encode_hex(point.y)]
+}
+
+pub fn encode_g2(point: &G2Affine) -> G2 {
+    [
+        [encode_hex(point.x.c1), encode_hex(point.x.c0)],
+        [encode_hex(point.y.c1), encode_hex(point.y.c0)],
+    ]
+}
+
+// arkworks 검증 키를 verification.key 형식으로 변환 (render_verifier로 verifier.sol 생성 가능)
+pub fn encode_verification_key(vk: &VerifyingKey<Bn254>) -> VerificationKey {
+    VerificationKey {
+        scheme: "g16".to_owned(),
+        curve: "bn128".to_owned(),
+        alpha: encode_g1(&vk.alpha_g1),
+        beta: encode_g2(&vk.beta_g2),
+        gamma: encode_g2(&vk.gamma_g2),
+        delta: encode_g2(&vk.delta_g2),
+        gamma_abc: vk.gamma_abc_g1.iter().map(encode_g1).collect(),
+    }
+}
+
+// arkworks 증명과 공개 입력을 proof.json 형식으로 변환
+pub fn encode_proof(proof: &Groth16Proof<Bn254>, inputs: &[Fr]) -> Proof {
+    Proof {
+        scheme: "g16".to_owned(),
+        curve: "bn128".to_owned(),
+        backend: Some("ark".to_owned()),
+        proof: json!({
+            "a": encode_g1(&proof.a),
+            "b": encode_g2(&proof.b),
+            "c": encode_g1(&proof.c),
+        }),
+        inputs: inputs.iter().copied().map(encode_hex).collect(),
+    }
+}
+
+// verifier.sol의 점 표현 (G1Point(x, y), G2Point([x.c1, x.c0], [y.c1, y.c0]))
+fn solidity_g1(point: &G1) -> String {
+    format!(
+        "Pairing.G1Point(uint256({}), uint256({}))",
+        point[0], point[1]
+    )
+}
+
+fn solidity_g2(point: &G2) -> String {
+    format!(
+        "Pairing.G2Point([uint256({}), uint256({})], [uint256({}), uint256({})])",
+        point[0][0], point[0][1], point[1][0], point[1][1]
+    )
+}
+
+// 검증 키로 Groth16 검증 컨트랙트(verifier.sol) 생성
+// `zokrates export-verifier`(g16, bn128)와 같은 인터페이스(Verifier.Proof, verifyTx)이므로 래퍼 컨트랙트가 그대로 씁니다.
+// 점은 verification.key와 같이 EIP-197 순서(허수부 먼저)로 쓰며, 곡선 위의 점이 아니면 오류입니다.
+pub fn render_verifier(key: &VerificationKey) -> Result<String> {
+    parse_verification_key(key)?;
+    let count =
+        key.gamma_abc.len().checked_sub(1).ok_or_else(|| {
+            CredentialError::parse("verification key", "gamma_abc must not be empty")
+        })?;
+    let gamma_abc = key
+        .gamma_abc
+        .iter()
+        .enumerate()
+        .map(|(i, point)| format!("        vk.gamma_abc[{}] = {};\n", i, solidity_g1(point)))
+        .collect::<String>();
+    Ok(format!(
+        r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+// 이 파일은 groth16.rs에서 검증 키로 생성됩니다.
+library Pairing {{
+    struct G1Point {{
+        uint X;
+        uint Y;
+    }}
+    // 확장체 원소는 X[0] * i + X[1]로 표현합니다.
+    struct G2Point {{
+        uint[2] X;
+        uint[2] Y;
+    }}
+
+    function negate(G1Point memory p) internal pure returns (G1Point memory) {{
+        uint q = 21888242871839275222246405745257275088696311157297823662689037894645226208583;
+        if (p.X == 0 && p.Y == 0) return G1Point(0, 0);
+        return G1Point(p.X, q - (p.Y % q));
+    }}
+
+    function addition(G1Point memory p1, G1Point memory p2) internal view returns (G1Point memory r) {{
+        uint[4] memory input = [p1.X, p1.Y, p2.X, p2.Y];
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 6, input, 0x80, r, 0x40)
+        }}
+        require(success, "G1 addition failed");
+    }}
+
+    function scalar_mul(G1Point memory p, uint s) internal view returns (G1Point memory r) {{
+        uint[3] memory input = [p.X, p.Y, s];
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 7, input, 0x60, r, 0x40)
+        }}
+        require(success, "G1 scalar multiplication failed");
+    }}
+
+    // e(a1, b1) * e(a2, b2) * e(a3, b3) * e(a4, b4) == 1
+    function pairingProd4(
+        G1Point memory a1, G2Point memory b1,
+        G1Point memory a2, G2Point memory b2,
+        G1Point memory a3, G2Point memory b3,
+        G1Point memory a4, G2Point memory b4
+    ) internal view returns (bool) {{
+        uint[24] memory input;
+        writePair(input, 0, a1, b1);
+        writePair(input, 6, a2, b2);
+        writePair(input, 12, a3, b3);
+        writePair(input, 18, a4, b4);
+        uint[1] memory out;
+        bool success;
+        assembly {{
+            success := staticcall(sub(gas(), 2000), 8, input, 0x300, out, 0x20)
+        }}
+        require(success, "Pairing check failed");
+        return out[0] != 0;
+    }}
+
+    function writePair(uint[24] memory input, uint offset, G1Point memory a, G2Point memory b) private pure {{
+        input[offset] = a.X;
+        input[offset + 1] = a.Y;
+        input[offset + 2] = b.X[0];
+        input[offset + 3] = b.X[1];
+        input[offset + 4] = b.Y[0];
+        input[offset + 5] = b.Y[1];
+    }}
+}}
+
+contract Verifier {{
+    struct VerifyingKey {{
+        Pairing.G1Point alpha;
+        Pairing.G2Point beta;
+        Pairing.G2Point gamma;
+        Pairing.G2Point delta;
+        Pairing.G1Point[] gamma_abc;
+    }}
+
+    struct Proof {{
+        Pairing.G1Point a;
+        Pairing.G2Point b;
+        Pairing.G1Point c;
+    }}
+
+    function verifyingKey() internal pure returns (VerifyingKey memory vk) {{
+        vk.alpha = {alpha};
+        vk.beta = {beta};
+        vk.gamma = {gamma};
+        vk.delta = {delta};
+        vk.gamma_abc = new Pairing.G1Point[]({points});
+{gamma_abc}    }}
+
+    // e(A, B) == e(alpha, beta) * e(vk_x, gamma) * e(C, delta)
+    function verifyTx(Proof memory proof, uint[{count}] memory input) public view returns (bool) {{
+        uint snark_scalar_field = 21888242871839275222246405745257275088548364400416034343698204186575808495617;
+        VerifyingKey memory vk = verifyingKey();
+        Pairing.G1Point memory vk_x = vk.gamma_abc[0];
+        for (uint i = 0; i < input.length; i++) {{
+            require(input[i] < snark_scalar_field, "Input is not in the scalar field");
+            vk_x = Pairing.addition(vk_x, Pairing.scalar_mul(vk.gamma_abc[i + 1], input[i]));
+        }}
+        return Pairing.pairingProd4(
+            proof.a, proof.b,
+            Pairing.negate(vk_x), vk.gamma,
+            Pairing.negate(proof.c), vk.delta,
+            Pairing.negate(vk.alpha), vk.beta
+        );
+    }}
+}}
+"#,
+        alpha = solidity_g1(&key.alpha),
+        beta = solidity_g2(&key.beta),
+        gamma = solidity_g2(&key.gamma),
+        delta = solidity_g2(&key.delta),
+        points = key.gamma_abc.len(),
+        gamma_abc = gamma_abc,
+        count = count,
+    ))
+}
@@ -0,0 +1,94 @@
+use crate::did::did_key;
+use crate::eddsa::derive_public_key;
+use crate::error::{read_file, write_file, CredentialError, Result};
+use crate::merkle::sha256_packed;
+use num_bigint::BigUint;
+use rand::Rng;
+
+// 홀더 커밋먼트의 도메인 구분 값 (리프 해시 sha256packed([0, 0, index, value])와 겹치지 않도록)
+const COMMITMENT_DOMAIN: u128 = 1;
+const CHALLENGE_DOMAIN: u128 = 2;
+const NULLIFIER_DOMAIN: u128 = 3;
+const PSEUDONYM_DOMAIN: u128 = 4;
+const DID_DOMAIN: u128 = 5;
+
+// 홀더 비밀값에 대한 커밋먼트 (zok/holder.zok의 holderCommitment)
+// 발급자는 이 값을 자격증명 리프로 서명하고, 증명 회로는 비밀값을 아는지 검사합니다.
+pub fn holder_commitment(secret: u128) -> u128 {
+    sha256_packed([COMMITMENT_DOMAIN, 0, 0, secret])[0]
+}
+
+// 검증자 챌린지에 대한 응답 (zok/holder.zok의 challengeResponse)
+// 증명의 공개 출력으로, 챌린지를 홀더 비밀값에 묶습니다.
+pub fn challenge_response(secret: u128, verifier_id: u128, nonce: u128) -> u128 {
+    sha256_packed([CHALLENGE_DOMAIN, secret, verifier_id, nonce])[0]
+}
+
+// 스코프별 널리파이어 (zok/holder.zok의 scopedNullifier)
+// 같은 홀더 비밀값과 스코프에서는 항상 같은 값이 나오지만, 홀더를 드러내지는 않습니다.
+pub fn scoped_nullifier(secret: u128, scope: u128) -> u128 {
+    sha256_packed([NULLIFIER_DOMAIN, secret, scope, 0])[0]
+}
+
+// 검증자 도메인별 가명 (zok/holder.zok의 verifierPseudonym)
+// 같은 검증자에게는 항상 같은 값이지만, 다른 검증자의 가명과는 연결할 수 없습니다.
+pub fn verifier_pseudonym(secret: u128, verifier_domain: u128) -> u128 {
+    sha256_packed([PSEUDONYM_DOMAIN, secret, verifier_domain, 0])[0]
+}
+
+// 홀더 DID의 BabyJubJub 비밀키 (256비트 해시, 스칼라 곱에서 곡선의 위수로 줄어듭니다.)
+// 커밋먼트, 널리파이어, 가명처럼 별도 도메인으로 유도하므로 홀더 비밀값을 서명 키로 직접 쓰지 않습니다.
+pub fn did_secret_key(secret: u128) -> BigUint {
+    let [high, low] = sha256_packed([DID_DOMAIN, secret, 0, 0]);
+    (BigUint::from(high) << 128u32) + low
+}
+
+// did_secret_key에 대응하는 공개키의 did:key (자격증명의 credential_subject.id)
+pub fn holder_did(secret: u128) -> String {
+    did_key(&derive_public_key(&did_secret_key(secret)))
+}
+
+// 새 홀더 비밀값 (128비트 난수)
+pub fn generate_secret() -> u128 {
+    rand::thread_rng().gen()
+}
+
+pub fn load_secret(secret_path: &str) -> Result<u128> {
+    read_file(secret_path)?
+        .trim()
+        .parse()
+        .map_err(|_| CredentialError::parse("holder secret", "must be a decimal number"))
+}
+
+// 홀더 비밀값을 <destination_path>/holder_secret에 저장하고 반환
+pub fn create_secret(destination_path: &str) -> Result<u128> {
+    let secret = generate_secret();
+    let secret_path = format!("{}/holder_secret", destination_path);
+    write_file(&secret_path, secret.to_string())?;
+    println!("Holder secret saved to: {}", secret_path);
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn holder_commitment_test() {
+        assert_eq!(holder_commitment(42), holder_commitment(42));
+        assert_ne!(holder_commitment(42), holder_commitment(43));
+        assert_ne!(holder_commitment(42), sha256_packed([0, 0, 0, 42])[0]);
+        assert_ne!(verifier_pseudonym(42, 7), verifier_pseudonym(42, 8));
+        assert_ne!(verifier_pseudonym(42, 7), scoped_nullifier(42, 7));
+        assert_eq!(
+            crate::did::public_key(&holder_did(42)),
+            Some(derive_public_key(&did_secret_key(42)))
+        );
+        // DID 키는 홀더 비밀값 자체가 아닙니다.
+        assert_ne!(did_secret_key(42), BigUint::from(42u8));
+        assert_ne!(
+            holder_did(42),
+            did_key(&derive_public_key(&BigUint::from(42u8)))
+        );
+    }
+}
@@ -1,59 +1,124 @@
-use crate::credential::{AlumniOf, Claims, CredentialIssuer, CredentialSubject};
-use chrono::{Duration, Utc};
-use serde_json::{json, to_string_pretty, to_value, Map, Value};
+use crate::backend::ZkBackend;
+use crate::config::Config;
+use crate::credential::{
+    day_number, AlumniOf, Attribute, Claims, Credential, CredentialIssuer, CredentialSubject,
+    ATTRIBUTES, DAY_EPOCH,
+};
+use crate::did;
+use crate::eddsa::{self, derive_public_key, Signature, ISSUER_PUBLIC_KEY};
+use crate::error::{
+    copy_file, create_dir, load_json, read_file, save_json, write_file, CredentialError, Result,
+};
+use crate::merkle::{message_words, MerkleTree, LEAF_COUNT};
+use crate::profile::IssuerProfile;
+use crate::revocation::{Registry, RevocationList};
+use crate::status_list::{status_entry, StatusList};
+use crate::workspace::Workspace;
+use chrono::{Duration, NaiveDate, Utc};
+use num_bigint::BigUint;
+use serde_json::{json, to_value, Value};
 use sha2::{Digest, Sha256};
 use std::{
-    fs::{self, File},
-    io::{BufReader, Write},
+    io,
     path::Path,
+    process::{Command, Stdio},
+    sync::Mutex,
 };
 
+// CLI의 발급자 디렉토리: 컴파일한 create_hash, 서명 스크립트, 발급 기록
+const ISSUER_DIR: &str = "./zok/issuer";
+// 레지스트리를 읽고 쓰는 동안 다른 스레드의 발급, 폐기를 막습니다.
+static REGISTRY_LOCK: Mutex<()> = Mutex::new(());
+
+// 발급 요청: 자격증명 주체의 속성
+pub struct CredentialRequest<'a> {
+    // 자격증명 주체의 did:key (holder::holder_did)
+    pub subject_id: &'a str,
+    pub name: &'a str,
+    pub age: u8,
+    pub birth_date: Option<NaiveDate>,
+    pub student_number: &'a str,
+    pub department: &'a str,
+    // 홀더가 제출한 비밀값 커밋먼트 (holder::holder_commitment)
+    pub holder_commitment: Option<u128>,
+}
+
+// 발급자 레지스트리: 발급한 자격증명의 폐기 인덱스와 폐기 목록
+fn registry_path(issuer_dir: &Path) -> String {
+    issuer_dir
+        .join("registry.json")
+        .to_str()
+        .unwrap()
+        .to_owned()
+}
+
+// profile: 발급 기관별 설정 (발급자, 컨텍스트, 자격증명 타입, 소속 기관, 유효 기간 등)
 pub fn create_claim(
     credential_id: &str,
-    name: &str,
-    age: u8,
-    student_number: &str,
-    department: &str,
-) {
-    let credential = Claims {
-        context: vec![
-            "https://www.w3.org/2018/credentials/v1".to_owned(),
-            "https://www.example.org/examples/v1".to_owned(),
-        ],
-        id: format!("http://chungnam.ac.kr/credentials/{}", credential_id).to_owned(),
-        credential_type: vec![
-            "VerifiableCredential".to_owned(),
-            "AlumniCredential".to_owned(),
-        ],
-        issuer: CredentialIssuer {
-            id: "https://infosec.chungnam.ac.kr".to_owned(),
-            name: "Chungnam National University Information Security Lab".to_owned(),
-        },
-        issuance_date: Utc::now(),
-        credential_subject: CredentialSubject {
-            id: "did:example:abcdef1234567890".to_owned(),
-            name: name.to_owned(),
-            age,
-            student_number: student_number.to_owned(),
-            alumni_of: AlumniOf {
-                id: "did:example:c34fb4561237890".to_owned(),
-                name: "Chungnam National University".to_owned(),
-                department: department.to_owned(),
-            },
-        },
-        exp: (Utc::now() + Duration::days(90)).timestamp(),
-    };
+    revocation_index: u64,
+    request: &CredentialRequest,
+    profile: &IssuerProfile,
+) -> Result<()> {
+    let issued = build_credential(credential_id, revocation_index, request, profile)?;
 
+    // 디렉토리 생성
+    let dir_path = format!("./zok/issuer/{}", credential_id);
+    create_dir(&dir_path)?;
+
+    // 파일에 JSON 데이터 쓰기 (가독성을 위해 예쁘게 인쇄)
+    let file_path = format!("./zok/issuer/{}/credential.json", credential_id);
+    save_json(file_path, &issued)
+}
+
+// 요청한 속성으로 자격증명을 만들고 속성 값을 머클 트리 리프 값으로 해시
+fn build_credential(
+    credential_id: &str,
+    revocation_index: u64,
+    request: &CredentialRequest,
+    profile: &IssuerProfile,
+) -> Result<Credential> {
+    if did::resolve(request.subject_id).is_none() {
+        return Err(CredentialError::InvalidInput(format!(
+            "Subject id must be a BabyJubJub did:key: {}",
+            request.subject_id
+        )));
+    }
+    let birth_date = request.birth_date.map(day_number);
+    if birth_date.is_some_and(|days| days < 0) {
+        return Err(CredentialError::InvalidInput(format!(
+            "Birth date must not be before {}",
+            DAY_EPOCH
+        )));
+    }
     let credential_subject = CredentialSubject {
-        id: "did:example:abcdef1234567890".to_owned(),
-        name: name.to_owned(),
-        age,
-        student_number: student_number.to_owned(),
+        id: request.subject_id.to_owned(),
+        name: request.name.to_owned(),
+        age: request.age,
+        birth_date,
+        student_number: request.student_number.to_owned(),
         alumni_of: AlumniOf {
-            id: "did:example:c34fb4561237890".to_owned(),
-            name: "Chungnam National University".to_owned(),
-            department: department.to_owned(),
+            id: profile.organization_id(),
+            name: profile.organization.name.clone(),
+            department: request.department.to_owned(),
         },
+        holder_commitment: request
+            .holder_commitment
+            .map(|commitment| commitment.to_string()),
+    };
+
+    let credential = Claims {
+        context: profile.context.clone(),
+        id: format!("{}{}", profile.credential_id_prefix, credential_id),
+        credential_type: profile.credential_type.clone(),
+        issuer: CredentialIssuer {
+            id: profile.issuer_id(),
+            name: profile.issuer_name.clone(),
+        },
+        issuance_date: Utc::now(),
+        credential_subject: credential_subject.clone(),
+        exp: (Utc::now() + Duration::days(profile.validity_days)).timestamp(),
+        revocation_index,
+        credential_status: status_entry(&profile.status_list_url, revocation_index),
     };
 
     let credential_serialized = to_value(&credential).expect("Failed to serialize credential");
@@ -64,6 +129,13 @@ pub fn create_claim(
 
     if let Value::Object(obj) = credential_serialized {
         for (key, value) in obj.iter() {
+            if let Value::Number(num) = value {
+                if num.is_i64() {
+                    // 만료 시각(exp)처럼 정수형인 값은 회로에서 비교할 수 있도록 직접 추가
+                    insert_leaf(&mut hashes, key, json!(num))?;
+                    continue;
+                }
+            }
             let value_str = value.to_string();
             // 값에 대한 SHA-256 해시 계산
             let mut hasher = Sha256::new();
@@ -73,22 +145,31 @@ pub fn create_claim(
             let hashed_decimal_str = hashed
                 .iter()
                 .flat_map(|&byte| format!("{}", byte).chars().collect::<Vec<_>>()) // 각 바이트를 10진수 문자열로 변환하고, 문자들을 flat_map을 사용해 하나의 이터레이터로 만듭니다.
-                .filter(|&c| c.is_digit(10)) // 0부터 9까지의 숫자인지 확인합니다.
+                .filter(|&c| c.is_ascii_digit()) // 0부터 9까지의 숫자인지 확인합니다.
                 .take(38) // 최초 38개의 문자만 선택합니다.
                 .collect::<String>(); // 필터링된 문자들을 문자열로 합칩니다.
 
             // 키와 해시된 값을 맵에 추가
-            hashes.insert(key.clone(), json!(hashed_decimal_str));
+            insert_leaf(&mut hashes, key, json!(hashed_decimal_str))?;
         }
     }
 
     // `serialized`가 객체인 경우, 그 키와 값을 순회
     if let Value::Object(obj) = credential_subject_serialized {
         for (key, value) in obj.iter() {
+            // 자격증명의 id와 겹치지 않도록 홀더 DID는 subject_id 리프에 둡니다.
+            let key = match key.as_str() {
+                "id" => "subject_id",
+                other => other,
+            };
             match value {
                 Value::Number(num) if num.is_i64() => {
                     // 숫자가 정수형일 경우, 직접 값을 추가
-                    hashes.insert(key.clone(), json!(num));
+                    insert_leaf(&mut hashes, key, json!(num))?;
+                }
+                Value::String(commitment) if key == "holder_commitment" => {
+                    // 홀더 커밋먼트는 회로에서 다시 계산하므로 값 그대로 추가
+                    insert_leaf(&mut hashes, key, json!(commitment))?;
                 }
                 _ => {
                     // 정수형이 아닌 경우, 값을 문자열로 변환하고 해시화
@@ -99,12 +180,12 @@ pub fn create_claim(
                     let hashed_decimal_str = hashed
                         .iter()
                         .flat_map(|&byte| format!("{}", byte).chars().collect::<Vec<_>>()) // 각 바이트를 10진수 문자열로 변환하고, 문자들을 flat_map을 사용해 하나의 이터레이터로 만듭니다.
-                        .filter(|&c| c.is_digit(10)) // 0부터 9까지의 숫자인지 확인합니다.
+                        .filter(|&c| c.is_ascii_digit()) // 0부터 9까지의 숫자인지 확인합니다.
                         .take(38) // 최초 38개의 문자만 선택합니다.
                         .collect::<String>(); // 필터링된 문자들을 문자열로 합칩니다.
 
                     // 키와 해시된 값을 맵에 추가
-                    hashes.insert(key.clone(), json!(hashed_decimal_str));
+                    insert_leaf(&mut hashes, key, json!(hashed_decimal_str))?;
                 }
             }
         }
@@ -114,189 +195,534 @@ pub fn create_claim(
     let json_hashes = serde_json::to_string(&hashes).expect("Failed to serialize hashes");
     println!("{}", json_hashes);
 
-    // JSON 객체를 문자열로 변환 (가독성을 위해 예쁘게 인쇄)
-    let json_hashes_pretty = to_string_pretty(&hashes).expect("Failed to serialize hashes");
-
-    // 디렉토리 생성
-    let dir_path = format!("./zok/issuer/{}", credential_id);
-    fs::create_dir_all(&dir_path).expect("Failed to create directory");
-
-    // 파일에 JSON 데이터 쓰기
-    let file_path = format!("./zok/issuer/{}/credential.json", credential_id);
-    let mut file = File::create(file_path).expect("Failed to create file");
-    file.write_all(json_hashes_pretty.as_bytes())
-        .expect("Failed to write to file");
+    // 해시된 값을 머클 트리 리프 순서대로 나열 (값이 없는 선택 속성은 제외)
+    let attributes = ATTRIBUTES
+        .iter()
+        .filter_map(|&name| {
+            let value = match hashes.get(name)? {
+                Value::String(val) => val.clone(),
+                value => value.to_string(),
+            };
+            Some(Attribute {
+                name: name.to_owned(),
+                value,
+            })
+        })
+        .collect();
+    Ok(Credential {
+        claim: credential,
+        attributes,
+    })
 }
 
-fn load_credential_hash(credential_id: &str) -> Vec<String> {
-    // 파일로부터 JSON 데이터 읽기
-    let file_path = format!("./zok/issuer/{}/credential.json", credential_id);
-    let data = fs::read_to_string(file_path).expect("Unable to read file");
+// 리프가 될 속성 값을 추가 (같은 키가 이미 있으면 덮어쓰지 않고 InvalidInput)
+fn insert_leaf(hashes: &mut serde_json::Map<String, Value>, key: &str, value: Value) -> Result<()> {
+    if hashes.contains_key(key) {
+        return Err(CredentialError::InvalidInput(format!(
+            "Duplicate credential attribute: {}",
+            key
+        )));
+    }
+    hashes.insert(key.to_owned(), value);
+    Ok(())
+}
 
-    // 읽은 데이터를 JSON으로 파싱
-    let hashes: Map<String, Value> = serde_json::from_str(&data).expect("Unable to parse JSON");
+// create_hash.zok의 인자: 리프 값과 값의 존재 여부 (빈 리프는 값 0, 존재 여부 0)
+// 자격증명은 발급자 디렉토리의 <id>/credential.json에서 읽습니다.
+fn load_credential_hash(issuer_dir: &Path, credential_id: &str) -> Result<Vec<String>> {
+    let file_path = issuer_dir.join(credential_id).join("credential.json");
+    let credential: Credential = load_json("credential", file_path)?;
+    let mut leaves = credential.leaves()?;
+    leaves.resize(LEAF_COUNT, None);
 
-    // JSON 객체에서 value 값만 추출하여 Vec<String>에 담아 리턴
-    hashes
+    let values = leaves.iter().map(|leaf| leaf.unwrap_or(0).to_string());
+    let present = leaves
         .iter()
-        .filter_map(|(_, value)| match value {
-            Value::String(val) => Some(val.clone()), // 값이 문자열인 경우
-            Value::Number(num) => Some(num.to_string()), // 값이 숫자인 경우, 숫자를 문자열로 변환
-            _ => None, // 그 외의 경우는 None을 반환하여 filter_map에서 제외
-        })
-        .collect()
+        .map(|leaf| if leaf.is_some() { "1" } else { "0" }.to_owned());
+    Ok(values.chain(present).collect())
 }
 
-use std::process::Command;
+// 파이썬 도구(venv, pip, 서명 스크립트) 실행, 실패하면 표준 에러 출력을 담은 Io 오류
+fn run_python(command: &mut Command, program: &Path) -> Result<()> {
+    let output = command
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|error| CredentialError::io(program, error))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(CredentialError::io(
+            program,
+            io::Error::other(format!("{} ({})", stderr.trim(), output.status)),
+        ));
+    }
+    Ok(())
+}
 
-pub fn setup() {
+pub fn setup(backend: &dyn ZkBackend) -> Result<()> {
     // `./zok` 폴더와 `./zok/issuer` 폴더 생성
-    fs::create_dir_all("./zok/issuer").expect("Failed to create directories");
+    create_dir(ISSUER_DIR)?;
 
     // 가상 환경 생성
-    let issuer_dir = Path::new("./zok/issuer");
+    let issuer_dir = Path::new(ISSUER_DIR);
 
     // `./zok/issuer/` 디렉토리에서 가상 환경 생성
-    let venv_creation = Command::new("python3")
-        .args(&["-m", "venv", "myvenv"])
-        .current_dir(&issuer_dir) // 현재 작업 디렉토리 설정
-        .status()
-        .expect("Failed to create virtual environment");
-
-    assert!(
-        venv_creation.success(),
-        "Virtual environment creation failed"
-    );
+    run_python(
+        Command::new("python3")
+            .args(["-m", "venv", "myvenv"])
+            .current_dir(issuer_dir), // 현재 작업 디렉토리 설정
+        Path::new("python3"),
+    )?;
 
     // `./zok/issuer/myvenv` 가상 환경에 'zokrates_pycrypto' 패키지 설치
-    let pip_install = Command::new("./myvenv/bin/pip")
-        .args(&["install", "zokrates_pycrypto"])
-        .current_dir(&issuer_dir) // 현재 작업 디렉토리 설정
-        .status()
-        .expect("Failed to install zokrates_pycrypto");
-
-    assert!(pip_install.success(), "Package installation failed");
+    run_python(
+        Command::new("./myvenv/bin/pip")
+            .args(["install", "zokrates_pycrypto"])
+            .current_dir(issuer_dir), // 현재 작업 디렉토리 설정
+        &issuer_dir.join("myvenv/bin/pip"),
+    )?;
 
     // 이후 단계: ZoKrates 도구를 사용하여 컴파일 등의 작업 수행
-    // 예: ZoKrates 컴파일 명령 실행
-    let compile_status = Command::new("zokrates")
-        .current_dir("./zok/issuer") // 작업 디렉토리 설정
-        .arg("compile")
-        .arg("-i")
-        .arg("../create_hash.zok")
-        .status() // 명령어 실행
-        .expect("Failed to execute zokrates compile");
-    assert!(compile_status.success()); // 컴파일 성공 확인
+    backend.compile(issuer_dir, "../create_hash.zok")
 }
 
-fn create_witness_for_eddsa_signature_memo(credential_id: &str) {
+// create_hash.zok의 출력 (서명할 메시지, u32 16개)
+fn create_witness_for_eddsa_signature_memo(
+    backend: &dyn ZkBackend,
+    workspace: &Workspace,
+    issuer_dir: &Path,
+    credential_id: &str,
+) -> Result<Vec<String>> {
     // credential_hash_param load
-    let credential_hash_param = load_credential_hash(credential_id);
-    let mut args = vec!["compute-witness".into(), "-a".into()];
-    args.extend(credential_hash_param.into_iter());
-    args.push("--verbose".into());
-    args.push("--json".into());
+    let credential_hash_param = load_credential_hash(issuer_dir, credential_id)?;
+    print!("args : {:?}", credential_hash_param);
+
+    let message = backend.compute_witness(workspace.path(), &credential_hash_param)?;
+    if message.len() != 16 {
+        return Err(CredentialError::parse(
+            "create_hash.zok output",
+            format!("expected 16 words, got {}", message.len()),
+        ));
+    }
+    Ok(message)
+}
 
-    print!("args : {:?}", args);
+// 메시지(u32 16개)에 대한 발급자 서명 ("R.x R.y S")
+// 스크립트의 입출력 파일(witness_values.json, signature)은 작업 디렉토리에 씁니다.
+// 스크립트가 만든 서명은 내보내기 전에 발급자 공개키로 검증합니다.
+fn sign(workspace: &Workspace, message: &[String]) -> Result<String> {
+    // Witness 값을 JSON 파일로 저장
+    let witness_file_path = workspace.path().join("witness_values.json");
+    let witness_json = serde_json::to_string(message).expect("Unable to serialize witness values");
+    write_file(witness_file_path, format!("{}\n", witness_json))?;
 
-    let compute_witness_status = Command::new("zokrates")
-        .current_dir("./zok/issuer") // 작업 디렉토리 설정
-        .args(&args)
-        .status() // 명령어 실행
-        .expect("Failed to execute zokrates compute-witness");
+    // Python 스크립트 실행하여 서명 및 공개키 생성
+    let issuer_dir = Path::new(ISSUER_DIR).canonicalize().map_err(|_| {
+        CredentialError::MissingSetup("Issuer directory not found. Setup first".to_owned())
+    })?;
+    let python = issuer_dir.join("myvenv/bin/python3");
+    run_python(
+        Command::new(&python)
+            .current_dir(workspace.path())
+            .arg(issuer_dir.join("create_signature.py")),
+        &python,
+    )?;
+
+    let signature = read_file(workspace.path().join("signature"))?
+        .trim()
+        .to_owned();
+    let words = message
+        .iter()
+        .map(|word| word.parse::<u32>().ok())
+        .collect::<Option<Vec<_>>>()
+        .and_then(|words| <[u32; 16]>::try_from(words).ok())
+        .ok_or_else(|| CredentialError::parse("message", "expected 16 u32 words"))?;
+    let valid = Signature::parse(&signature).is_some_and(|parsed| {
+        eddsa::verify(&eddsa::constant_point(ISSUER_PUBLIC_KEY), &parsed, &words)
+    });
+    if !valid {
+        return Err(CredentialError::InvalidSignature(
+            "Signing script produced a signature that does not verify under the issuer key"
+                .to_owned(),
+        ));
+    }
+    Ok(signature)
+}
 
-    assert!(compute_witness_status.success()); // compute-witness 성공 확인
+// 레지스트리에 자격증명을 등록하고 폐기 인덱스를 예약
+// 동시에 발급해도 폐기 인덱스가 겹치지 않도록 레지스트리는 잠근 채 갱신합니다.
+// (발급이 도중에 실패해도 예약한 인덱스는 다시 쓰지 않습니다.)
+fn register(issuer_dir: &Path, credential_id: &str) -> Result<u64> {
+    let _lock = REGISTRY_LOCK
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    create_dir(issuer_dir)?;
+    let registry_path = registry_path(issuer_dir);
+    let mut registry = Registry::load(&registry_path)?;
+    let index = registry.register(credential_id)?;
+    registry.save(&registry_path)?;
+    Ok(index)
 }
 
-fn load_zokrates_witness() -> Vec<String> {
-    let path = Path::new("./zok/issuer/witness.json");
-    // 파일을 열고 BufReader에 바인딩합니다.
-    let file = File::open(path).expect("Failed to open file");
-    let reader = BufReader::new(file);
+// 레지스트리에 폐기를 기록하고 자격증명의 폐기 인덱스를 반환
+fn revoke_registered(issuer_dir: &Path, credential_id: &str) -> Result<u64> {
+    let _lock = REGISTRY_LOCK
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    let registry_path = registry_path(issuer_dir);
+    let mut registry = Registry::load(&registry_path)?;
+    let index = registry.revoke(credential_id)?;
+    registry.save(&registry_path)?;
+    Ok(index)
+}
 
-    // JSON 데이터를 파싱합니다.
-    let v: Map<String, Value> = serde_json::from_reader(reader).unwrap();
+// 폐기 레지스트리의 루트에 서명한 폐기 목록
+// sign: 메시지(u32 16개)에 대한 발급자 서명 ("R.x R.y S")
+fn signed_revocation_list(
+    issuer_dir: &Path,
+    sign: impl Fn(&[u32; 16]) -> Result<String>,
+) -> Result<RevocationList> {
+    let registry = Registry::load(&registry_path(issuer_dir))?;
+    let root = registry.tree()?.root();
+    let signature = sign(&message_words(&root))?;
+    Ok(RevocationList {
+        revoked: registry.revoked,
+        root: root.map(|value| value.to_string()),
+        published: Utc::now(),
+        signature,
+    })
+}
 
-    // 특정 키(`~out_0` 부터 `~out_15`)에 해당하는 값들을 순서대로 추출합니다.
-    let mut values = Vec::new();
+// 폐기 레지스트리로 만든 서명한 상태 목록
+fn signed_status_list(
+    issuer_dir: &Path,
+    profile: &IssuerProfile,
+    sign: impl Fn(&[u32; 16]) -> Result<String>,
+) -> Result<StatusList> {
+    let registry = Registry::load(&registry_path(issuer_dir))?;
+    let mut list = StatusList::new(
+        &profile.status_list_url,
+        &profile.issuer_id(),
+        &registry.revoked,
+        registry.next_index,
+    )?;
+    list.signature = sign(&message_words(&list.digest()))?;
+    Ok(list)
+}
 
-    for i in 0..16 {
-        let key = format!("~out_{}", i);
-        if let Some(Value::String(val)) = v.get(&key) {
-            values.push(val.clone());
-        }
-    }
-    values
+// 발급 기록 없이 서명 스크립트로 메시지에 서명 (CLI)
+fn sign_with_script(message: &[u32; 16]) -> Result<String> {
+    sign(
+        &Workspace::new(Path::new(ISSUER_DIR))?,
+        &message.map(|word| word.to_string()),
+    )
 }
 
-// Witness 값 로드, Python 스크립트 실행하여 서명 및 공개키 생성, 파일 이동까지 포함하는 함수
+// Witness 값 로드, Python 스크립트 실행하여 서명 및 공개키 생성, 파일 복사까지 포함하는 함수
+// 발급한 자격증명과 서명은 ./zok/issuer/<id>에도 남겨 둡니다.
 pub fn create_credential(
+    backend: &dyn ZkBackend,
     credential_id: &str,
-    name: &str,
-    age: u8,
-    student_number: &str,
-    department: &str,
+    request: &CredentialRequest,
+    profile: &IssuerProfile,
     signature_save_path: &str,
-) {
-    create_claim(credential_id, name, age, student_number, department);
-    create_witness_for_eddsa_signature_memo(credential_id);
-    // Witness 값 로드
-    let witness_values = load_zokrates_witness(); // 이 함수의 구현체는 제공되지 않았으므로 가정
+) -> Result<()> {
+    let issuer_dir = Path::new(ISSUER_DIR);
+    let revocation_index = register(issuer_dir, credential_id)?;
+    create_claim(credential_id, revocation_index, request, profile)?;
+    let workspace = Workspace::new(issuer_dir)?;
+    let witness_values =
+        create_witness_for_eddsa_signature_memo(backend, &workspace, issuer_dir, credential_id)?;
+    let signature = sign(&workspace, &witness_values)?;
+
+    // 서명을 발급 기록에 남기고, 자격증명과 함께 저장 경로로 복사
+    let record_path = format!("./zok/issuer/{}/signature", credential_id);
+    write_file(&record_path, signature)?;
+    for file_name in ["credential.json", "signature"] {
+        let source_path = format!("./zok/issuer/{}/{}", credential_id, file_name);
+        let destination_path = format!("{}/{}", signature_save_path, file_name);
+
+        copy_file(&source_path, &destination_path)?;
+        println!("File copied to: {}", destination_path);
+    }
+    Ok(())
+}
 
-    // Witness 값을 JSON 파일로 저장
-    let witness_file_path = "./zok/issuer/witness_values.json";
-    let mut file = File::create(witness_file_path).expect("Unable to create witness file");
-    let witness_json =
-        serde_json::to_string(&witness_values).expect("Unable to serialize witness values");
-    writeln!(file, "{}", witness_json).expect("Unable to write witness values");
+// 자격증명을 폐기 레지스트리에 추가 (publish_revocation_root로 공개해야 반영됩니다.)
+pub fn revoke(credential_id: &str) -> Result<()> {
+    let index = revoke_registered(Path::new(ISSUER_DIR), credential_id)?;
+    println!(
+        "Revoked credential {} (revocation index {})",
+        credential_id, index
+    );
+    Ok(())
+}
 
-    // Python 스크립트 실행하여 서명 및 공개키 생성
-    let output = Command::new("./myvenv/bin/python3")
-        .current_dir("./zok/issuer")
-        .arg("create_signature.py")
-        .output()
-        .expect("Failed to execute python script");
-    assert!(output.status.success(), "Python script execution failed");
-
-    // 파일 이동 (signature 및 credential.json)
-    let file_name = "credential.json";
-    let source_path = format!("./zok/issuer/{}/{}", credential_id, file_name);
-    let destination_path = format!("{}/{}", signature_save_path, file_name);
-
-    fs::rename(&source_path, &destination_path).expect(&format!(
-        "Failed to move {} to {}",
-        source_path, destination_path
-    ));
-    println!("File moved to: {}", destination_path);
-    let file_name = "signature";
-    let source_path = format!("./zok/issuer/{}", file_name);
-    let destination_path = format!("{}/{}", signature_save_path, file_name);
-
-    fs::rename(&source_path, &destination_path).expect(&format!(
-        "Failed to move {} to {}",
-        source_path, destination_path
-    ));
-    println!("File moved to: {}", destination_path);
+// 폐기 레지스트리의 루트에 서명하여 revocation_list.json으로 공개
+pub fn publish_revocation_root(destination_path: &str) -> Result<()> {
+    let list = signed_revocation_list(Path::new(ISSUER_DIR), sign_with_script)?;
+    let list_path = format!("{}/revocation_list.json", destination_path);
+    save_json(&list_path, &list)?;
+    println!("Revocation list saved to: {}", list_path);
+    Ok(())
+}
+
+// 폐기 레지스트리를 상태 목록으로 서명하여 status_list.json으로 공개
+// 회로에서 폐기 검사를 하지 않는 검증자는 이 목록으로 자격증명의 상태를 확인합니다.
+pub fn publish_status_list(profile: &IssuerProfile, destination_path: &str) -> Result<()> {
+    let list = signed_status_list(Path::new(ISSUER_DIR), profile, sign_with_script)?;
+    let list_path = format!("{}/status_list.json", destination_path);
+    save_json(&list_path, &list)?;
+    println!("Status list saved to: {}", list_path);
+    Ok(())
+}
+
+// 발급자 공개키를 <destination_path>/issuer_public_key로 내보내기 ("A.x A.y")
+// 여러 자격증명 증명에서 홀더와 검증자가 발급자를 구분하는 데 씁니다.
+pub fn export_public_key(destination_path: &str) -> Result<()> {
+    let key_path = format!("{}/issuer_public_key", destination_path);
+    write_file(&key_path, ISSUER_PUBLIC_KEY.join(" "))?;
+    println!("Issuer public key saved to: {}", key_path);
+    Ok(())
+}
+
+// 설정(Config)의 발급자 키로 자격증명을 발급하는 발급자
+// 서명은 프로세스 안에서 만들며(eddsa::sign), 자격증명과 서명을 파일로 쓰지 않고 값으로 반환합니다.
+// 파일로 남기는 것은 <root>/issuer/registry.json(폐기 레지스트리)뿐입니다.
+#[derive(Debug, Clone)]
+pub struct Issuer {
+    config: Config,
+    profile: IssuerProfile,
+    secret_key: BigUint,
+}
+
+impl Issuer {
+    pub fn new(config: Config, mut profile: IssuerProfile) -> Result<Issuer> {
+        let secret_key = config.issuer_secret_key.clone().ok_or_else(|| {
+            CredentialError::InvalidInput("Issuer requires the issuer secret key".to_owned())
+        })?;
+        let public_key =
+            derive_public_key(&secret_key).map(|value| BigUint::from(value).to_string());
+        if public_key != config.issuer_public_key {
+            return Err(CredentialError::InvalidInput(
+                "Issuer secret key does not match the issuer public key".to_owned(),
+            ));
+        }
+        if !config.issuer_set {
+            config.check_fixed_issuer_key()?;
+        }
+        // 프로필에 발급자 DID가 없으면 설정의 발급자 공개키로 만듭니다.
+        if profile.issuer_id.is_none() {
+            let key = &config.issuer_public_key;
+            profile.issuer_id = Some(did::did_key(&eddsa::point([&key[0], &key[1]])?));
+        }
+        Ok(Issuer {
+            config,
+            profile,
+            secret_key,
+        })
+    }
+
+    pub fn public_key(&self) -> &[String; 2] {
+        &self.config.issuer_public_key
+    }
+
+    // 메시지(u32 16개)에 대한 발급자 서명
+    pub fn sign(&self, message: &[u32; 16]) -> Signature {
+        eddsa::sign(&self.secret_key, message)
+    }
+
+    // 폐기 인덱스를 예약하고 자격증명과 그 머클 루트에 대한 서명을 반환
+    pub fn issue(
+        &self,
+        credential_id: &str,
+        request: &CredentialRequest,
+    ) -> Result<(Credential, Signature)> {
+        let revocation_index = register(&self.config.issuer_dir(), credential_id)?;
+        let credential = build_credential(credential_id, revocation_index, request, &self.profile)?;
+        let root = MerkleTree::new(&credential.leaves()?)?.root();
+        let signature = self.sign(&message_words(&root));
+        Ok((credential, signature))
+    }
+
+    // 자격증명을 폐기하고 폐기 인덱스를 반환 (revocation_list, status_list에 반영됩니다.)
+    pub fn revoke(&self, credential_id: &str) -> Result<u64> {
+        revoke_registered(&self.config.issuer_dir(), credential_id)
+    }
+
+    pub fn revocation_list(&self) -> Result<RevocationList> {
+        signed_revocation_list(&self.config.issuer_dir(), |message| {
+            Ok(self.sign(message).to_string())
+        })
+    }
+
+    pub fn status_list(&self) -> Result<StatusList> {
+        signed_status_list(&self.config.issuer_dir(), &self.profile, |message| {
+            Ok(self.sign(message).to_string())
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::backend::{MockBackend, ZokratesCli};
+    use crate::credential::leaf_index;
+    use crate::merkle::MerkleTree;
+    use std::{env, fs};
 
     #[test]
     fn setup_test() {
-        setup();
+        setup(&ZokratesCli::default()).unwrap();
     }
 
     #[test]
     fn create_claim_test() {
+        let subject_id = crate::holder::holder_did(42);
+        let request = CredentialRequest {
+            subject_id: &subject_id,
+            name: "Socrates",
+            age: 30,
+            birth_date: NaiveDate::from_ymd_opt(1994, 3, 7),
+            student_number: "201902769",
+            department: "Information Security",
+            holder_commitment: None,
+        };
         create_credential(
+            &ZokratesCli::default(),
             "3732",
-            "Socrates",
-            30,
-            "201902769",
-            "Information Security",
+            &request,
+            &IssuerProfile::default(),
             "./zok/prover",
+        )
+        .unwrap();
+    }
+
+    // 내장 키가 아닌 발급자 키는 발급자 집합 회로를 쓸 때만 허용
+    #[test]
+    fn issuer_key_test() {
+        let secret_key = BigUint::from(12345u32);
+        let mut config = Config::new(env::temp_dir().join("issuer_key_test"));
+        config.issuer_public_key =
+            derive_public_key(&secret_key).map(|value| BigUint::from(value).to_string());
+        config.issuer_secret_key = Some(secret_key);
+        assert!(matches!(
+            Issuer::new(config.clone(), IssuerProfile::default()),
+            Err(CredentialError::InvalidInput(_))
+        ));
+        config.issuer_set = true;
+        assert!(Issuer::new(config, IssuerProfile::default()).is_ok());
+    }
+
+    // 1970년 이전에 태어난 홀더의 생년월일도 음수가 아닌 리프 값
+    #[test]
+    fn birth_date_test() {
+        let subject_id = crate::holder::holder_did(8);
+        let mut request = CredentialRequest {
+            subject_id: &subject_id,
+            name: "Diogenes",
+            age: 71,
+            birth_date: NaiveDate::from_ymd_opt(1955, 3, 7),
+            student_number: "197402769",
+            department: "Philosophy",
+            holder_commitment: None,
+        };
+        let profile = IssuerProfile::default();
+        let credential = build_credential("birth-date", 0, &request, &profile).unwrap();
+        let leaf = credential.leaves().unwrap()[leaf_index("birth_date").unwrap()];
+        assert_eq!(leaf, Some(20153));
+        assert_eq!(day_number(DAY_EPOCH), 0);
+
+        request.birth_date = NaiveDate::from_ymd_opt(1899, 12, 31);
+        assert!(matches!(
+            build_credential("birth-date", 0, &request, &profile),
+            Err(CredentialError::InvalidInput(_))
+        ));
+    }
+
+    // 자격증명의 id와 홀더 DID는 서로 다른 리프에 들어가고, 겹치는 키는 거부
+    #[test]
+    fn subject_id_test() {
+        let subject_id = crate::holder::holder_did(9);
+        let mut request = CredentialRequest {
+            subject_id: &subject_id,
+            name: "Aristotle",
+            age: 40,
+            birth_date: None,
+            student_number: "198402769",
+            department: "Philosophy",
+            holder_commitment: None,
+        };
+        let profile = IssuerProfile::default();
+        let credential = build_credential("subject-id", 0, &request, &profile).unwrap();
+        let credential_leaf = credential.value("id").unwrap().to_owned();
+        let subject_leaf = credential.value("subject_id").unwrap().to_owned();
+        assert_ne!(credential_leaf, subject_leaf);
+
+        let other_id = crate::holder::holder_did(10);
+        request.subject_id = &other_id;
+        let other = build_credential("subject-id", 0, &request, &profile).unwrap();
+        assert_eq!(other.value("id"), Some(credential_leaf.as_str()));
+        assert_ne!(other.value("subject_id"), Some(subject_leaf.as_str()));
+
+        let mut hashes = serde_json::Map::new();
+        insert_leaf(&mut hashes, "id", json!("1")).unwrap();
+        assert!(matches!(
+            insert_leaf(&mut hashes, "id", json!("2")),
+            Err(CredentialError::InvalidInput(_))
+        ));
+        assert_eq!(hashes["id"], json!("1"));
+    }
+
+    #[test]
+    fn credential_hash_test() {
+        let subject_id = crate::holder::holder_did(7);
+        let request = CredentialRequest {
+            subject_id: &subject_id,
+            name: "Plato",
+            age: 25,
+            birth_date: None,
+            student_number: "202012345",
+            department: "Philosophy",
+            holder_commitment: None,
+        };
+        // 발급자 디렉토리 대신 임시 디렉토리에 자격증명과 모의 컴파일 결과를 둡니다.
+        let issuer_dir = Workspace::temp().unwrap();
+        let credential =
+            build_credential("mock-3733", 0, &request, &IssuerProfile::default()).unwrap();
+        create_dir(issuer_dir.path().join("mock-3733")).unwrap();
+        save_json(
+            issuer_dir.path().join("mock-3733/credential.json"),
+            &credential,
+        )
+        .unwrap();
+        fs::write(issuer_dir.path().join("out"), "mock").unwrap();
+        let message = message_words(
+            &MerkleTree::new(&credential.leaves().unwrap())
+                .unwrap()
+                .root(),
+        );
+        let backend = MockBackend::with_outputs(message.map(|word| word.to_string()).to_vec());
+
+        let workspace = Workspace::new(issuer_dir.path()).unwrap();
+        let witness = create_witness_for_eddsa_signature_memo(
+            &backend,
+            &workspace,
+            issuer_dir.path(),
+            "mock-3733",
+        )
+        .unwrap();
+        assert_eq!(witness, backend.outputs);
+        // 인자는 리프 값 16개와 존재 여부 16개
+        let args = load_credential_hash(issuer_dir.path(), "mock-3733").unwrap();
+        assert_eq!(args.len(), 2 * LEAF_COUNT);
+        assert_eq!(
+            args[LEAF_COUNT..]
+                .iter()
+                .filter(|flag| *flag == "1")
+                .count(),
+            credential.leaves().unwrap().iter().flatten().count()
         );
+        assert_eq!(backend.calls(), ["compute-witness 32"]);
     }
 }
@@ -0,0 +1,173 @@
+use crate::eddsa::{self, load_public_key};
+use crate::error::{load_json, path_str, save_json, CredentialError, Result};
+use crate::merkle::{node_hash, sha256_packed, Node, EMPTY_LEAF};
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+// 신뢰하는 발급자 집합 머클 트리의 깊이 (zok/merkle.zok의 ISSUER_SET_DEPTH와 같아야 합니다.)
+pub const ISSUER_SET_DEPTH: usize = 8;
+pub const ISSUER_SET_CAPACITY: usize = 1 << ISSUER_SET_DEPTH;
+
+// 발급자 공개키의 리프 (zok/merkle.zok의 issuerLeaf)
+// 좌표마다 상위, 하위 128비트로 나누어 sha256packed([x_hi, x_lo, y_hi, y_lo])를 계산합니다.
+// 필드 원소가 아니거나 곡선 위의 점이 아닌 키는 회로에서 서명을 검증할 수 없으므로 거부합니다.
+pub fn issuer_leaf(key: &[String; 2]) -> Result<Node> {
+    let mask = (BigUint::from(1u8) << 128) - 1u8;
+    let mut preimage = [0u128; 4];
+    for (i, coordinate) in eddsa::point([&key[0], &key[1]])?.into_iter().enumerate() {
+        let value = BigUint::from(coordinate);
+        preimage[2 * i] = u128::try_from(&(&value >> 128)).unwrap();
+        preimage[2 * i + 1] = u128::try_from(&(&value & &mask)).unwrap();
+    }
+    Ok(sha256_packed(preimage))
+}
+
+// 검증자가 신뢰하는 발급자 공개키 집합과 그 머클 루트 (trusted_issuers.json)
+// 회로는 서명한 발급자의 키가 이 루트 아래에 있음만 증명하므로, 검증자는 어느 발급자인지 알 수 없습니다.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TrustedIssuers {
+    // 리프 순서의 발급자 공개키 (10진수)
+    pub keys: Vec<[String; 2]>,
+    pub root: [String; 2],
+}
+
+impl TrustedIssuers {
+    // 키를 정렬하고 중복을 제거하여 루트를 계산
+    pub fn new(mut keys: Vec<[String; 2]>) -> Result<TrustedIssuers> {
+        keys.sort();
+        keys.dedup();
+        if keys.len() > ISSUER_SET_CAPACITY {
+            return Err(CredentialError::InvalidInput(format!(
+                "At most {} issuers can be trusted",
+                ISSUER_SET_CAPACITY
+            )));
+        }
+        let mut issuers = TrustedIssuers {
+            keys,
+            root: Default::default(),
+        };
+        issuers.root = issuers.compute_root()?.map(|value| value.to_string());
+        Ok(issuers)
+    }
+
+    // 디렉토리의 모든 발급자 공개키 파일(issuer export_public_key 출력)로 집합 구성
+    pub fn from_dir(keys_dir: &str) -> Result<TrustedIssuers> {
+        let entries =
+            fs::read_dir(keys_dir).map_err(|error| CredentialError::io(keys_dir, error))?;
+        let mut keys = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|error| CredentialError::io(keys_dir, error))?
+                .path();
+            if path.is_file() {
+                keys.push(load_public_key(&path_str(&path)?)?);
+            }
+        }
+        TrustedIssuers::new(keys)
+    }
+
+    pub fn load(issuers_path: &str) -> Result<TrustedIssuers> {
+        load_json("trusted issuers", issuers_path)
+    }
+
+    pub fn save(&self, issuers_path: &str) -> Result<()> {
+        save_json(issuers_path, self)
+    }
+
+    // layers[0]은 리프 (남는 리프는 빈 리프), 마지막 층은 루트
+    fn layers(&self) -> Result<Vec<Vec<Node>>> {
+        let mut leaves = self
+            .keys
+            .iter()
+            .map(issuer_leaf)
+            .collect::<Result<Vec<_>>>()?;
+        leaves.resize(ISSUER_SET_CAPACITY, EMPTY_LEAF);
+        let mut layers = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| node_hash(&pair[0], &pair[1]))
+                .collect();
+            layers.push(next);
+        }
+        Ok(layers)
+    }
+
+    pub fn compute_root(&self) -> Result<Node> {
+        Ok(self.layers()?.last().unwrap()[0])
+    }
+
+    // 기록된 루트가 키 목록과 맞는지 확인 (키를 해석할 수 없으면 false)
+    pub fn verify(&self) -> bool {
+        match self.compute_root() {
+            Ok(root) => self.root == root.map(|value| value.to_string()),
+            Err(_) => false,
+        }
+    }
+
+    pub fn position(&self, key: &[String; 2]) -> Option<usize> {
+        self.keys.iter().position(|trusted| trusted == key)
+    }
+
+    // 리프에서 루트까지의 형제 노드 목록 (아래층부터)
+    pub fn path(&self, index: usize) -> Result<Vec<Node>> {
+        Ok(self.layers()?[..ISSUER_SET_DEPTH]
+            .iter()
+            .enumerate()
+            .map(|(level, layer)| layer[(index >> level) ^ 1])
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eddsa::ISSUER_PUBLIC_KEY;
+    use ark_ed_on_bn254::Fq;
+    use ark_ff::PrimeField;
+    use std::str::FromStr;
+
+    #[test]
+    fn membership_test() {
+        let issuer = ISSUER_PUBLIC_KEY.map(str::to_owned);
+        let other = ["0".to_owned(), "1".to_owned()];
+        let issuers =
+            TrustedIssuers::new(vec![issuer.clone(), other.clone(), issuer.clone()]).unwrap();
+        assert_eq!(issuers.keys.len(), 2);
+        assert!(issuers.verify());
+        assert_eq!(issuer_leaf(&other).unwrap(), sha256_packed([0, 0, 0, 1]));
+        assert!(TrustedIssuers::new(vec![["x".to_owned(), "2".to_owned()]]).is_err());
+
+        let index = issuers.position(&issuer).unwrap();
+        let mut node = issuer_leaf(&issuer).unwrap();
+        for (level, sibling) in issuers.path(index).unwrap().iter().enumerate() {
+            node = if (index >> level) & 1 == 1 {
+                node_hash(sibling, &node)
+            } else {
+                node_hash(&node, sibling)
+            };
+        }
+        assert_eq!(node, issuers.compute_root().unwrap());
+    }
+
+    // 모듈러스 이상의 좌표나 곡선 밖의 점은 집합에 넣지 않습니다.
+    #[test]
+    fn invalid_key_test() {
+        let x = BigUint::from_str(ISSUER_PUBLIC_KEY[0]).unwrap();
+        let congruent = [
+            (x + BigUint::from(Fq::MODULUS)).to_string(),
+            ISSUER_PUBLIC_KEY[1].to_owned(),
+        ];
+        let off_curve = ["1".to_owned(), "2".to_owned()];
+        for key in [congruent, off_curve] {
+            assert!(matches!(
+                issuer_leaf(&key),
+                Err(CredentialError::InvalidInput(_))
+            ));
+            assert!(TrustedIssuers::new(vec![key]).is_err());
+        }
+    }
+}
@@ -1,4 +1,24 @@
+pub mod backend;
+pub mod ceremony;
+pub mod challenge;
+pub mod circuit;
+pub mod config;
 pub mod credential;
+pub mod did;
+pub mod eddsa;
+pub mod error;
+pub mod groth16;
+pub mod holder;
 pub mod issuer;
+pub mod issuer_set;
+pub mod merkle;
+pub mod nullifier;
+pub mod profile;
+pub mod proof;
 pub mod prover;
+pub mod proving_system;
+pub mod r1cs;
+pub mod revocation;
+pub mod status_list;
 pub mod verifier;
+pub mod workspace;
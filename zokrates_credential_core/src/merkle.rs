@@ -0,0 +1,163 @@
+use crate::error::{CredentialError, Result};
+use sha2::{Digest, Sha256};
+
+// 자격증명 머클 트리의 깊이 (zok/merkle.zok의 DEPTH와 같아야 합니다.)
+pub const DEPTH: usize = 4;
+// 리프 개수: 속성은 최대 16개까지 담을 수 있습니다.
+pub const LEAF_COUNT: usize = 1 << DEPTH;
+
+// 노드는 ZoKrates sha256packed의 출력처럼 128비트 필드 두 개로 표현합니다.
+pub type Node = [u128; 2];
+
+// ZoKrates `hashes/sha256/512bitPacked`와 같은 해시
+// 네 개의 128비트 값을 빅엔디언으로 이어 붙인 512비트에 SHA-256을 적용합니다.
+pub fn sha256_packed(preimage: [u128; 4]) -> Node {
+    let mut hasher = Sha256::new();
+    for value in preimage {
+        hasher.update(value.to_be_bytes());
+    }
+    let digest = hasher.finalize();
+    let mut high = [0u8; 16];
+    let mut low = [0u8; 16];
+    high.copy_from_slice(&digest[..16]);
+    low.copy_from_slice(&digest[16..]);
+    [u128::from_be_bytes(high), u128::from_be_bytes(low)]
+}
+
+// 속성 값의 리프 커밋먼트 (zok/merkle.zok의 leafHash)
+pub fn leaf_hash(index: usize, value: u128) -> Node {
+    sha256_packed([0, 0, index as u128, value])
+}
+
+// 값이 없는 리프 (어떤 속성 값의 leaf_hash와도 같을 수 없습니다.)
+pub const EMPTY_LEAF: Node = [0, 0];
+
+// 두 자식 노드로부터 부모 노드 계산
+pub fn node_hash(left: &Node, right: &Node) -> Node {
+    sha256_packed([left[0], left[1], right[0], right[1]])
+}
+
+// 리프 인덱스를 회로에 넘길 방향 비트로 변환 (true면 현재 노드가 오른쪽 자식)
+pub fn directions(index: usize) -> [bool; DEPTH] {
+    let mut directions = [false; DEPTH];
+    for (level, direction) in directions.iter_mut().enumerate() {
+        *direction = (index >> level) & 1 == 1;
+    }
+    directions
+}
+
+pub struct MerkleTree {
+    // layers[0]은 리프, 마지막 층은 루트
+    layers: Vec<Vec<Node>>,
+}
+
+impl MerkleTree {
+    // 리프 위치별 속성 값으로 트리 구성 (값이 없거나 남는 리프는 빈 리프)
+    pub fn new(values: &[Option<u128>]) -> Result<MerkleTree> {
+        if values.len() > LEAF_COUNT {
+            return Err(CredentialError::InvalidInput(format!(
+                "A credential can hold at most {} attributes",
+                LEAF_COUNT
+            )));
+        }
+        let leaves = (0..LEAF_COUNT)
+            .map(|index| match values.get(index).copied().flatten() {
+                Some(value) => leaf_hash(index, value),
+                None => EMPTY_LEAF,
+            })
+            .collect::<Vec<_>>();
+
+        let mut layers = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| node_hash(&pair[0], &pair[1]))
+                .collect();
+            layers.push(next);
+        }
+        Ok(MerkleTree { layers })
+    }
+
+    pub fn root(&self) -> Node {
+        self.layers.last().unwrap()[0]
+    }
+
+    // 리프에서 루트까지의 형제 노드 목록 (아래층부터)
+    pub fn path(&self, index: usize) -> Vec<Node> {
+        self.layers[..DEPTH]
+            .iter()
+            .enumerate()
+            .map(|(level, layer)| layer[(index >> level) ^ 1])
+            .collect()
+    }
+}
+
+// 서명 대상 메시지: 루트의 각 필드를 256비트로 풀어 쓴 u32[8] 두 개 (M0, M1)
+pub fn message_words(root: &Node) -> [u32; 16] {
+    let mut words = [0u32; 16];
+    for (half, value) in root.iter().enumerate() {
+        for i in 0..4 {
+            words[half * 8 + 4 + i] = (value >> (96 - 32 * i)) as u32;
+        }
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha256_packed_test() {
+        // ZoKrates 문서의 sha256packed([0, 0, 0, 5]) 결과
+        assert_eq!(
+            sha256_packed([0, 0, 0, 5]),
+            [
+                263561599766550617289250058199814760685,
+                65303172752238645975888084098459749904
+            ]
+        );
+    }
+
+    #[test]
+    fn path_test() {
+        let values = (0..11)
+            .map(|value| if value == 3 { None } else { Some(value * 1000) })
+            .collect::<Vec<Option<u128>>>();
+        let tree = MerkleTree::new(&values).unwrap();
+
+        for (index, value) in values.iter().enumerate() {
+            let Some(value) = *value else {
+                continue;
+            };
+            let mut node = leaf_hash(index, value);
+            for (sibling, right) in tree.path(index).iter().zip(directions(index)) {
+                node = if right {
+                    node_hash(sibling, &node)
+                } else {
+                    node_hash(&node, sibling)
+                };
+            }
+            assert_eq!(node, tree.root());
+        }
+    }
+
+    // 리프 수보다 많은 값은 패닉 없이 거부
+    #[test]
+    fn capacity_test() {
+        assert!(MerkleTree::new(&[Some(1); LEAF_COUNT]).is_ok());
+        assert!(matches!(
+            MerkleTree::new(&[Some(1); LEAF_COUNT + 1]),
+            Err(CredentialError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn message_words_test() {
+        let words = message_words(&[1 << 96 | 2, 3]);
+        assert_eq!(words[..8], [0, 0, 0, 0, 1, 0, 0, 2]);
+        assert_eq!(words[8..], [0, 0, 0, 0, 0, 0, 0, 3]);
+    }
+}
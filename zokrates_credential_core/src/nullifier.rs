@@ -0,0 +1,77 @@
+use crate::error::{load_json, save_json, Result};
+use crate::proof::text_field;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+// 스코프(투표, 에어드롭 등 한 번만 허용할 단위)를 회로에 넣을 128비트 값으로 변환
+pub fn scope_field(scope: &str) -> u128 {
+    text_field(scope)
+}
+
+// 널리파이어 문자열의 값 (앞에 0이나 +가 붙는 등 정규 10진수 표현이 아니면 None)
+// 같은 널리파이어를 다른 문자열로 다시 내지 못하도록 저장과 조회는 이 값으로만 합니다.
+pub fn parse_nullifier(nullifier: &str) -> Option<u128> {
+    nullifier
+        .parse::<u128>()
+        .ok()
+        .filter(|value| value.to_string() == nullifier)
+}
+
+// 검증자가 받아들인 널리파이어 목록 (스코프별)
+// 같은 홀더는 같은 스코프에서 항상 같은 널리파이어를 내므로 두 번째 사용을 거절할 수 있습니다.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct NullifierStore {
+    pub used: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl NullifierStore {
+    // 파일이 없으면 빈 목록
+    pub fn load(store_path: &str) -> Result<NullifierStore> {
+        if !Path::new(store_path).exists() {
+            return Ok(NullifierStore::default());
+        }
+        load_json("nullifier store", store_path)
+    }
+
+    pub fn save(&self, store_path: &str) -> Result<()> {
+        save_json(store_path, self)
+    }
+
+    pub fn contains(&self, scope: &str, nullifier: u128) -> bool {
+        self.used
+            .get(scope)
+            .is_some_and(|nullifiers| nullifiers.contains(&nullifier.to_string()))
+    }
+
+    // 처음 사용된 널리파이어면 기록하고 true
+    pub fn insert(&mut self, scope: &str, nullifier: u128) -> bool {
+        self.used
+            .entry(scope.to_owned())
+            .or_default()
+            .insert(nullifier.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nullifier_store_test() {
+        let mut store = NullifierStore::default();
+        assert!(store.insert("election-2026", 1234));
+        assert!(store.contains("election-2026", 1234));
+        assert!(!store.contains("airdrop", 1234));
+        assert!(!store.insert("election-2026", 1234));
+
+        // 정규 10진수 표현만 널리파이어로 받아들임
+        assert_eq!(parse_nullifier("1234"), Some(1234));
+        assert_eq!(parse_nullifier("0"), Some(0));
+        for nullifier in ["01234", "+1234", " 1234", "", "-1"] {
+            assert_eq!(parse_nullifier(nullifier), None);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::did::issuer_did;
+use crate::error::{load_json, Result};
+use crate::status_list::STATUS_LIST_URL;
+use serde::{Deserialize, Serialize};
+
+// 자격증명 주체의 소속 기관 (credential_subject.alumni_of)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Organization {
+    // 없으면 발급자 id를 사용
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+}
+
+// 발급 기관별 설정 (issuer --profile <path>로 지정하는 JSON 파일)
+// 지정하지 않으면 충남대학교 정보보호연구실의 기본값을 사용합니다.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct IssuerProfile {
+    // 없으면 발급자 공개키의 did:key를 사용
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_id: Option<String>,
+    pub issuer_name: String,
+    pub context: Vec<String>,
+    pub credential_type: Vec<String>,
+    pub organization: Organization,
+    // 자격증명 id는 이 접두사 뒤에 발급 시 지정한 id를 붙입니다.
+    pub credential_id_prefix: String,
+    // 발급일부터 만료(exp)까지의 일 수
+    pub validity_days: i64,
+    // 발급자가 상태 목록을 공개하는 주소
+    pub status_list_url: String,
+}
+
+impl Default for IssuerProfile {
+    fn default() -> IssuerProfile {
+        IssuerProfile {
+            issuer_id: None,
+            issuer_name: "Chungnam National University Information Security Lab".to_owned(),
+            context: vec![
+                "https://www.w3.org/2018/credentials/v1".to_owned(),
+                "https://www.example.org/examples/v1".to_owned(),
+            ],
+            credential_type: vec![
+                "VerifiableCredential".to_owned(),
+                "AlumniCredential".to_owned(),
+            ],
+            organization: Organization {
+                id: None,
+                name: "Chungnam National University".to_owned(),
+            },
+            credential_id_prefix: "http://chungnam.ac.kr/credentials/".to_owned(),
+            validity_days: 90,
+            status_list_url: STATUS_LIST_URL.to_owned(),
+        }
+    }
+}
+
+impl IssuerProfile {
+    pub fn load(profile_path: &str) -> Result<IssuerProfile> {
+        load_json("issuer profile", profile_path)
+    }
+
+    pub fn issuer_id(&self) -> String {
+        self.issuer_id.clone().unwrap_or_else(issuer_did)
+    }
+
+    pub fn organization_id(&self) -> String {
+        self.organization
+            .id
+            .clone()
+            .unwrap_or_else(|| self.issuer_id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_profile_test() {
+        let example: IssuerProfile =
+            serde_json::from_str(include_str!("../../issuer_profile.example.json")).unwrap();
+        assert_eq!(example, IssuerProfile::default());
+        assert_eq!(example.organization_id(), issuer_did());
+        assert!(serde_json::from_str::<IssuerProfile>(r#"{"issuer_name": "x"}"#).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::challenge::Challenge;
+use crate::error::{load_json, Result};
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::{collections::BTreeMap, str::FromStr};
+
+// `zokrates generate-proof`가 생성하는 proof.json 형식
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Proof {
+    pub scheme: String,
+    pub curve: String,
+    // 증명을 생성한 백엔드 (ark, bellman), 프루버가 zokrates의 출력에 추가합니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backend: Option<String>,
+    pub proof: Value,
+    pub inputs: Vec<String>,
+}
+
+impl Proof {
+    pub fn load(proof_path: &str) -> Result<Proof> {
+        load_json("proof", proof_path)
+    }
+}
+
+// 검증자에게 전달되는 프레젠테이션: 증명과 함께 공개 입력의 값을 담습니다.
+// 공개 입력의 순서는 circuit::public_inputs를 따릅니다.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Presentation {
+    pub proof: Proof,
+    pub min_age: u8,
+    // 증명 생성 시각 (Unix timestamp), 회로는 exp > current_time을 검사합니다.
+    pub current_time: i64,
+    // 생년월일로 나이를 검사한 경우 기준 날짜 (credential::day_number)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub today: Option<i64>,
+    // 폐기 레지스트리 검사를 한 경우 기준이 된 공개 루트 (10진수)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revocation_root: Option<[String; 2]>,
+    // 발급자 집합 검사를 한 경우 신뢰하는 발급자 집합의 루트 (10진수)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_root: Option<[String; 2]>,
+    // 검증자 챌린지에 응답한 경우 챌린지와 응답(공개 출력, 10진수)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<Challenge>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub challenge_response: Option<String>,
+    // 널리파이어를 계산한 경우 스코프와 널리파이어(공개 출력, 10진수)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nullifier: Option<String>,
+    // 가명을 계산한 경우 검증자 도메인과 가명(공개 출력, 10진수)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verifier_domain: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pseudonym: Option<String>,
+    pub disclosed: BTreeMap<String, String>,
+}
+
+impl Presentation {
+    pub fn load(presentation_path: &str) -> Result<Presentation> {
+        load_json("presentation", presentation_path)
+    }
+}
+
+// 여러 자격증명을 묶은 증명의 프레젠테이션 (circuit::MultiCircuitSpec)
+// 공개 입력의 순서는 MultiCircuitSpec::public_inputs를 따릅니다.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MultiPresentation {
+    pub proof: Proof,
+    pub current_time: i64,
+    // 자격증명별 발급자 공개키 (10진수)
+    pub issuer_keys: Vec<[String; 2]>,
+    // 자격증명별로 공개한 속성
+    pub disclosed: Vec<BTreeMap<String, String>>,
+}
+
+impl MultiPresentation {
+    pub fn load(presentation_path: &str) -> Result<MultiPresentation> {
+        load_json("presentation", presentation_path)
+    }
+}
+
+// 문자열(검증자 id, 스코프 등)을 회로에 넣을 128비트 값으로 변환 (SHA-256의 앞 16바이트)
+pub fn text_field(text: &str) -> u128 {
+    let digest = Sha256::digest(text.as_bytes());
+    let mut high = [0u8; 16];
+    high.copy_from_slice(&digest[..16]);
+    u128::from_be_bytes(high)
+}
+
+// 공개 입력은 0x 접두사가 붙은 32바이트 16진수 문자열로 기록됩니다.
+pub fn encode_public_input(value: u128) -> String {
+    format!("0x{:064x}", value)
+}
+
+// 128비트를 넘는 필드 원소(발급자 공개키 등)를 10진수 문자열에서 공개 입력 형식으로 변환
+pub fn encode_field(decimal: &str) -> Option<String> {
+    BigUint::from_str(decimal)
+        .ok()
+        .map(|value| format!("0x{:064x}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_public_input_test() {
+        assert_eq!(
+            encode_public_input(20),
+            "0x0000000000000000000000000000000000000000000000000000000000000014"
+        );
+        assert_eq!(encode_field("20"), Some(encode_public_input(20)));
+        assert_eq!(
+            encode_field(
+                "14897476871502190904409029696666322856887678969656209656241038339251270171395"
+            ),
+            Some("0x20efac506a3d21dc3882103a7a727ad821f2422988783ee8b2f1762ecd0dbb03".to_owned())
+        );
+    }
+}
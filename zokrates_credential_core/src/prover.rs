@@ -1,112 +1,565 @@
-use serde_json::{Map, Value};
-use std::{
-    fs::{self, File},
-    io::{self, BufRead},
+use crate::{
+    backend::ZkBackend,
+    challenge::{verifier_field, Challenge},
+    circuit::{Circuit, CircuitSpec, MultiCircuitSpec},
+    config::{install_libraries, Config},
+    credential::{day_number, leaf_index, Credential},
+    eddsa::{self, load_public_key, point, Signature, ISSUER_PUBLIC_KEY},
+    error::{path_str, read_file, save_json, CredentialError, Result},
+    holder::{
+        challenge_response, holder_commitment, load_secret, scoped_nullifier, verifier_pseudonym,
+    },
+    issuer_set::TrustedIssuers,
+    merkle::{message_words, MerkleTree},
+    nullifier::scope_field,
+    proof::{text_field, MultiPresentation, Presentation, Proof},
+    proving_system::proving_key_path,
+    revocation::RevocationList,
+    workspace::Workspace,
 };
+use chrono::Utc;
+use num_bigint::BigUint;
+use std::path::Path;
 
-fn load_credential(credential_path: &str) -> Vec<String> {
-    // 파일로부터 JSON 데이터 읽기
-    let data = fs::read_to_string(credential_path).expect("Unable to read file");
+// 여는 리프마다 값과 머클 경로(형제 노드들)를 회로 인자 순서대로 나열
+fn load_credential_openings(credential: &Credential, opened: &[&str]) -> Result<Vec<String>> {
+    let tree = MerkleTree::new(&credential.leaves()?)?;
 
-    // 읽은 데이터를 JSON으로 파싱
-    let hashes: Map<String, Value> = serde_json::from_str(&data).expect("Unable to parse JSON");
+    let mut param = Vec::new();
+    for attribute in opened {
+        let value = credential.value(attribute).ok_or_else(|| {
+            CredentialError::UnsatisfiedPredicate(format!(
+                "Credential has no attribute: {}",
+                attribute
+            ))
+        })?;
+        param.push(value.to_owned());
+        for sibling in tree.path(leaf_index(attribute)?) {
+            param.extend(sibling.iter().map(u128::to_string));
+        }
+    }
+    Ok(param)
+}
 
-    // JSON 객체에서 value 값만 추출하여 Vec<String>에 담아 리턴
-    hashes
-        .iter()
-        .filter_map(|(_, value)| match value {
-            Value::String(val) => Some(val.clone()), // 값이 문자열인 경우
-            Value::Number(num) => Some(num.to_string()), // 값이 숫자인 경우, 숫자를 문자열로 변환
-            _ => None, // 그 외의 경우는 None을 반환하여 filter_map에서 제외
-        })
-        .collect()
-}
-
-fn load_signature(signature_path: &str) -> Result<Vec<String>, io::Error> {
-    // 파일 경로로부터 파일을 엽니다.
-    let file = File::open(signature_path)?;
-    let reader = io::BufReader::new(file);
-
-    // 파일의 첫 번째 줄을 읽습니다.
-    let mut lines = reader.lines();
-    if let Some(Ok(line)) = lines.next() {
-        // 공백으로 값을 분리하여 Vec<String>에 저장합니다.
-        let values = line
-            .split_whitespace()
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
-        Ok(values)
-    } else {
-        Err(io::Error::new(
-            io::ErrorKind::Other,
-            "The file is empty or not accessible",
-        ))
-    }
-}
-
-use std::process::Command;
-
-pub fn setup() {
-    // `zokrates compile` 명령어 실행
-    let compile_status = Command::new("zokrates")
-        .current_dir("./zok/prover") // 작업 디렉토리 설정
-        .arg("compile")
-        .arg("-i")
-        .arg("verify_credential.zok")
-        .status() // 명령어 실행
-        .expect("Failed to execute zokrates compile");
-    assert!(compile_status.success()); // 컴파일 성공 확인
-}
-
-fn create_witness_for_verify_credential(credential_path: &str, signature_path: &str) {
+// 서명 파일의 첫 줄 "R.x R.y S"
+fn load_signature(signature_path: &str) -> Result<Signature> {
+    let data = read_file(signature_path)?;
+    Signature::parse(data.lines().next().unwrap_or_default())
+        .ok_or_else(|| CredentialError::parse("signature", "expected R.x R.y S"))
+}
+
+// 회로의 서명 인자 R.x, R.y, S (10진수)
+fn signature_param(signature: &Signature) -> [String; 3] {
+    [
+        BigUint::from(signature.r[0]).to_string(),
+        BigUint::from(signature.r[1]).to_string(),
+        signature.s.to_string(),
+    ]
+}
+
+// 자격증명의 머클 루트에 대한 서명이 발급자 공개키로 검증되는지 확인
+fn signed_by(
+    credential: &Credential,
+    signature: &Signature,
+    issuer_key: &[String; 2],
+) -> Result<bool> {
+    let root = MerkleTree::new(&credential.leaves()?)?.root();
+    Ok(eddsa::verify(
+        &point([&issuer_key[0], &issuer_key[1]])?,
+        signature,
+        &message_words(&root),
+    ))
+}
+
+// 회로 구성에 필요한 입력이 빠졌을 때의 오류
+fn required<T>(value: Option<T>, message: &str) -> Result<T> {
+    value.ok_or_else(|| CredentialError::InvalidInput(message.to_owned()))
+}
+
+// spec: 공개 속성, 나이 검사 방식 등 회로 구성
+pub fn setup(backend: &dyn ZkBackend, spec: &impl Circuit) -> Result<()> {
+    let circuit_dir = spec.prepare("./zok/prover")?;
+    backend.compile(&circuit_dir, "verify_credential.zok")
+}
+
+fn create_witness_for_verify_credential(
+    backend: &dyn ZkBackend,
+    circuit_dir: &Path,
+    credential: &Credential,
+    opened: &[&str],
+    signature_param: Vec<String>,
+    public_param: Vec<String>,
+    private_param: Vec<String>,
+) -> Result<()> {
     // credential_hash_param load
-    let credential_hash_param = load_credential(credential_path);
-    let signature_param = load_signature(signature_path).unwrap();
+    let credential_hash_param = load_credential_openings(credential, opened)?;
 
-    // 세 가지 파라미터를 하나의 Vec<String>으로 합치기
-    let mut param = Vec::new();
+    // 파라미터들을 하나의 Vec<String>으로 합치기
+    // 고정 공개 입력(CircuitSpec::public_params)은 main 함수의 맨 앞 인자이고,
+    // 폐기 레지스트리 경로, 홀더 비밀값 등은 여는 리프들과 서명 사이에 옵니다.
+    let mut param = public_param;
     param.extend(credential_hash_param);
+    param.extend(private_param);
     param.extend(signature_param);
+    backend.compute_witness(circuit_dir, &param)?;
+    Ok(())
+}
 
-    let mut args = vec!["compute-witness".into(), "-a".into()];
-    args.extend(param.into_iter());
-    args.push("--verbose".into());
-    args.push("--json".into());
-
-    let compute_witness_status = Command::new("zokrates")
-        .current_dir("./zok/prover") // 작업 디렉토리 설정
-        .args(&args)
-        .status() // 명령어 실행
-        .expect("Failed to execute zokrates compute-witness");
+// 회로 구성에 따라 필요한 자격증명 외의 입력 파일
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProofOptions<'a> {
+    // spec.revocation: 발급자가 공개한 폐기 목록(revocation_list.json)
+    pub revocation_list_path: Option<&'a str>,
+    // spec.issuer_set: 검증자가 공개한 신뢰하는 발급자 집합(trusted_issuers.json)
+    pub trusted_issuers_path: Option<&'a str>,
+    // spec.holder_binding: 홀더 비밀값 파일(holder_secret)
+    pub holder_secret_path: Option<&'a str>,
+    // spec.challenge: 검증자가 발행한 챌린지(challenge.json)
+    pub challenge_path: Option<&'a str>,
+    // spec.nullifier: 검증자가 정한 스코프 (예: election-2026)
+    pub scope: Option<&'a str>,
+    // spec.pseudonym: 가명을 받을 검증자 도메인 (예: shop.example)
+    pub verifier_domain: Option<&'a str>,
+}
 
-    assert!(compute_witness_status.success()); // compute-witness 성공 확인
+// ProofOptions의 파일 대신 값으로 받는 입력 (Prover::prove)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProofInputs<'a> {
+    pub revocation_list: Option<&'a RevocationList>,
+    pub trusted_issuers: Option<&'a TrustedIssuers>,
+    pub holder_secret: Option<u128>,
+    pub challenge: Option<&'a Challenge>,
+    pub scope: Option<&'a str>,
+    pub verifier_domain: Option<&'a str>,
 }
 
+// 증명(proof.json)과 공개한 속성을 담은 프레젠테이션(presentation.json)을 생성
+// 증명 키는 같은 구성(spec)으로 설정한 회로의 것이어야 합니다.
+// 서명, 폐기 여부, 홀더 비밀값 등은 회로에 넣기 전에 확인하여 InvalidSignature, UnsatisfiedPredicate로 알립니다.
+#[allow(clippy::too_many_arguments)]
 pub fn create_proof(
+    backend: &dyn ZkBackend,
     credential_path: &str,
     signature_path: &str,
     proving_key_path: &str,
+    min_age: u8,
+    spec: &CircuitSpec,
+    options: &ProofOptions,
     destination_path: &str,
-) {
-    create_witness_for_verify_credential(credential_path, signature_path);
-
-    // `generate-proof` 명령어에 필요한 인자를 새로운 방식으로 준비합니다.
-    let args: Vec<String> = vec![
-        "generate-proof".into(),
-        "--proving-key-path".into(),
-        proving_key_path.into(), // 프루빙 키 경로
-        "--witness".into(),
-        "witness".into(), // compute-witness에서 생성된 증인 파일의 이름
-        "--proof-path".into(),
-        format!("{}/proof.json", destination_path).into(), // 생성된 증명을 저장할 경로
-    ];
-
-    // `zokrates generate-proof` 명령어를 수정된 인자로 실행합니다.
-    let generate_proof_status = Command::new("zokrates")
-        .current_dir("./zok/prover") // 작업 디렉토리 설정
-        .args(&args)
-        .status() // 명령어 실행
-        .expect("Failed to execute zokrates generate-proof");
-
-    assert!(generate_proof_status.success()); // generate-proof 성공 확인
+) -> Result<()> {
+    let credential = Credential::load(credential_path)?;
+    let signature = load_signature(signature_path)?;
+    let revocation_list = options
+        .revocation_list_path
+        .map(RevocationList::load)
+        .transpose()?;
+    let trusted_issuers = options
+        .trusted_issuers_path
+        .map(TrustedIssuers::load)
+        .transpose()?;
+    let challenge = options.challenge_path.map(Challenge::load).transpose()?;
+    let inputs = ProofInputs {
+        revocation_list: revocation_list.as_ref(),
+        trusted_issuers: trusted_issuers.as_ref(),
+        holder_secret: options.holder_secret_path.map(load_secret).transpose()?,
+        challenge: challenge.as_ref(),
+        scope: options.scope,
+        verifier_domain: options.verifier_domain,
+    };
+    // 발급자 집합을 쓰지 않는 회로는 eddsa::ISSUER_PUBLIC_KEY로 서명을 검증합니다.
+    let presentation = prove(
+        backend,
+        &spec.dir("./zok/prover"),
+        proving_key_path,
+        &ISSUER_PUBLIC_KEY.map(str::to_owned),
+        &credential,
+        &signature,
+        min_age,
+        spec,
+        &inputs,
+    )?;
+
+    // 증명과, 공개한 속성 값을 증명과 함께 담은 presentation.json을 저장
+    save_json(
+        format!("{}/proof.json", destination_path),
+        &presentation.proof,
+    )?;
+    let presentation_path = format!("{}/presentation.json", destination_path);
+    save_json(&presentation_path, &presentation)?;
+    println!("Presentation saved to: {}", presentation_path);
+    Ok(())
+}
+
+// circuit_dir의 회로로 자격증명에 대한 증명을 만들어 프레젠테이션으로 반환
+// issuer_public_key: 발급자 집합을 쓰지 않는 회로에서 서명을 확인할 발급자 공개키
+#[allow(clippy::too_many_arguments)]
+fn prove(
+    backend: &dyn ZkBackend,
+    circuit_dir: &Path,
+    proving_key_path: &str,
+    issuer_public_key: &[String; 2],
+    credential: &Credential,
+    signature: &Signature,
+    min_age: u8,
+    spec: &CircuitSpec,
+    inputs: &ProofInputs,
+) -> Result<Presentation> {
+    // 증인과 증명은 작업마다 만드는 임시 디렉토리에서 계산 (컴파일된 회로는 공유)
+    let workspace = Workspace::new(circuit_dir)?;
+    if !spec.issuer_set && !signed_by(credential, signature, issuer_public_key)? {
+        return Err(CredentialError::InvalidSignature(
+            "Credential is not signed by the issuer".to_owned(),
+        ));
+    }
+    // 회로는 자격증명의 만료 시각이 이 시각 이후인지 검사합니다.
+    let current_time = Utc::now().timestamp();
+    // 생년월일 검사는 오늘 날짜(일 수)를 기준으로 합니다.
+    let today = spec.birth_date.then(|| day_number(Utc::now().date_naive()));
+
+    let mut public_param = vec![min_age.to_string(), current_time.to_string()];
+    public_param.extend(today.map(|today| today.to_string()));
+
+    // 폐기 레지스트리의 공개 루트와 자격증명 폐기 인덱스의 (빈) 리프 경로
+    let mut revocation_root = None;
+    let mut private_param = Vec::new();
+    if spec.revocation {
+        let list = required(
+            inputs.revocation_list,
+            "Non-revocation proof requires a revocation list",
+        )?;
+        let tree = list.tree()?;
+        let index = credential.claim.revocation_index;
+        if tree.is_revoked(index) {
+            return Err(CredentialError::UnsatisfiedPredicate(
+                "Credential has been revoked".to_owned(),
+            ));
+        }
+        if list.root != tree.root().map(|value| value.to_string()) {
+            return Err(CredentialError::InvalidInput(
+                "Revocation list root does not match its entries".to_owned(),
+            ));
+        }
+        for sibling in tree.path(index) {
+            private_param.extend(sibling.iter().map(u128::to_string));
+        }
+        public_param.extend(list.root.iter().cloned());
+        revocation_root = Some(list.root.clone());
+    }
+
+    // 신뢰하는 발급자 집합의 루트와, 서명한 발급자 키의 위치와 경로
+    let mut issuer_root = None;
+    if spec.issuer_set {
+        let issuers = required(
+            inputs.trusted_issuers,
+            "Issuer set proof requires the trusted issuers",
+        )?;
+        if !issuers.verify() {
+            return Err(CredentialError::InvalidInput(
+                "Trusted issuers root does not match its keys".to_owned(),
+            ));
+        }
+        let mut index = None;
+        for (i, key) in issuers.keys.iter().enumerate() {
+            if signed_by(credential, signature, key)? {
+                index = Some(i);
+                break;
+            }
+        }
+        let index = index.ok_or_else(|| {
+            CredentialError::UnsatisfiedPredicate(
+                "Credential is not signed by a trusted issuer".to_owned(),
+            )
+        })?;
+        private_param.extend(issuers.keys[index].iter().cloned());
+        private_param.push(index.to_string());
+        for sibling in issuers.path(index)? {
+            private_param.extend(sibling.iter().map(u128::to_string));
+        }
+        public_param.extend(issuers.root.iter().cloned());
+        issuer_root = Some(issuers.root.clone());
+    }
+
+    // 자격증명에 서명된 커밋먼트의 홀더 비밀값
+    let mut holder_secret = None;
+    if spec.holder_binding {
+        let secret = required(
+            inputs.holder_secret,
+            "Holder binding requires the holder secret",
+        )?;
+        if credential.value("holder_commitment") != Some(&holder_commitment(secret).to_string()) {
+            return Err(CredentialError::UnsatisfiedPredicate(
+                "Holder secret does not match the credential's holder commitment".to_owned(),
+            ));
+        }
+        private_param.push(secret.to_string());
+        holder_secret = Some(secret);
+    }
+
+    // 검증자 챌린지와, 회로가 공개 출력으로 내는 챌린지 응답
+    let mut challenge = None;
+    let mut response = None;
+    if spec.challenge {
+        let issued = required(
+            inputs.challenge,
+            "Challenge proof requires the verifier's challenge",
+        )?;
+        let verifier_id = verifier_field(&issued.verifier_id);
+        let secret = required(
+            holder_secret,
+            "A verifier challenge requires holder binding",
+        )?;
+        public_param.extend([verifier_id.to_string(), issued.nonce.clone()]);
+        response = Some(challenge_response(secret, verifier_id, issued.nonce()?).to_string());
+        challenge = Some(issued.clone());
+    }
+
+    // 스코프와, 회로가 공개 출력으로 내는 널리파이어
+    let mut scope = None;
+    let mut nullifier = None;
+    if spec.nullifier {
+        let name = required(inputs.scope, "Nullifier proof requires a scope")?;
+        let secret = required(holder_secret, "A nullifier requires holder binding")?;
+        public_param.push(scope_field(name).to_string());
+        nullifier = Some(scoped_nullifier(secret, scope_field(name)).to_string());
+        scope = Some(name.to_owned());
+    }
+
+    // 검증자 도메인과, 회로가 공개 출력으로 내는 가명
+    let mut verifier_domain = None;
+    let mut pseudonym = None;
+    if spec.pseudonym {
+        let domain = required(
+            inputs.verifier_domain,
+            "Pseudonym proof requires the verifier domain",
+        )?;
+        let secret = required(holder_secret, "A pseudonym requires holder binding")?;
+        public_param.push(text_field(domain).to_string());
+        pseudonym = Some(verifier_pseudonym(secret, text_field(domain)).to_string());
+        verifier_domain = Some(domain.to_owned());
+    }
+    create_witness_for_verify_credential(
+        backend,
+        workspace.path(),
+        credential,
+        &spec.opened_attributes(),
+        signature_param(signature).to_vec(),
+        public_param,
+        private_param,
+    )?;
+    let proof = generate_proof(backend, workspace.path(), proving_key_path)?;
+
+    Ok(Presentation {
+        proof,
+        min_age,
+        current_time,
+        today,
+        revocation_root,
+        issuer_root,
+        challenge,
+        challenge_response: response,
+        scope,
+        nullifier,
+        verifier_domain,
+        pseudonym,
+        disclosed: spec
+            .reveal
+            .iter()
+            .map(|&attribute| {
+                let value = credential.value(attribute).unwrap();
+                (attribute.to_owned(), value.to_owned())
+            })
+            .collect(),
+    })
+}
+
+// 작업 디렉토리(circuit_dir)에서 계산한 증인으로 증명을 만들어 반환
+// 증명 키가 다른 증명 시스템으로 설정되었으면 증명하기 전에 거부합니다.
+fn generate_proof(
+    backend: &dyn ZkBackend,
+    circuit_dir: &Path,
+    proving_key_path: &str,
+) -> Result<Proof> {
+    let system = backend.proving_system();
+    system.check_key(proving_key_path)?;
+    let proof_path = path_str(&circuit_dir.join("proof.json"))?; // 생성된 증명을 저장할 경로
+    backend.generate_proof(circuit_dir, proving_key_path, &proof_path)?;
+
+    // 증명을 생성한 백엔드를 증명에 기록
+    let mut proof = Proof::load(&proof_path)?;
+    proof.backend.get_or_insert(system.backend.clone());
+    system.check_proof(&proof)?;
+    Ok(proof)
+}
+
+// 여러 자격증명 증명의 입력: 자격증명, 서명, 서명한 발급자의 공개키 파일
+#[derive(Debug, Clone, Copy)]
+pub struct CredentialInput<'a> {
+    pub credential_path: &'a str,
+    pub signature_path: &'a str,
+    pub issuer_public_key_path: &'a str,
+}
+
+// 여러 자격증명(발급자가 다를 수 있음)을 하나로 묶은 증명과 프레젠테이션을 생성
+// inputs는 spec의 자격증명 순서를 따르며, 홀더 바인딩에는 모든 자격증명에 공통인 홀더 비밀값이 필요합니다.
+pub fn create_multi_proof(
+    backend: &dyn ZkBackend,
+    inputs: &[CredentialInput],
+    proving_key_path: &str,
+    spec: &MultiCircuitSpec,
+    holder_secret_path: Option<&str>,
+    destination_path: &str,
+) -> Result<()> {
+    if inputs.len() != spec.count() {
+        return Err(CredentialError::InvalidInput(format!(
+            "The circuit expects {} credentials",
+            spec.count()
+        )));
+    }
+    let workspace = Workspace::new(&spec.dir("./zok/prover"))?;
+    let current_time = Utc::now().timestamp();
+    let credentials = inputs
+        .iter()
+        .map(|input| Credential::load(input.credential_path))
+        .collect::<Result<Vec<_>>>()?;
+    let issuer_keys = inputs
+        .iter()
+        .map(|input| load_public_key(input.issuer_public_key_path))
+        .collect::<Result<Vec<_>>>()?;
+    let signatures = inputs
+        .iter()
+        .map(|input| load_signature(input.signature_path))
+        .collect::<Result<Vec<_>>>()?;
+
+    // 회로에 넣기 전에 서명과 자격증명 사이의 제약을 확인
+    for (i, credential) in credentials.iter().enumerate() {
+        if !signed_by(credential, &signatures[i], &issuer_keys[i])? {
+            return Err(CredentialError::InvalidSignature(format!(
+                "Credential {} is not signed by its issuer key",
+                i
+            )));
+        }
+    }
+    for attribute in &spec.equal {
+        if !credentials
+            .iter()
+            .all(|credential| credential.value(attribute) == credentials[0].value(attribute))
+        {
+            return Err(CredentialError::UnsatisfiedPredicate(format!(
+                "Credentials differ in {}",
+                attribute
+            )));
+        }
+    }
+
+    // main 함수 인자 순서: 증명 시각, 발급자 공개키, 자격증명별 여는 리프, 홀더 비밀값, 서명 R, S
+    let signatures = signatures.iter().map(signature_param).collect::<Vec<_>>();
+    let mut param = vec![current_time.to_string()];
+    param.extend(issuer_keys.iter().flatten().cloned());
+    for (i, credential) in credentials.iter().enumerate() {
+        param.extend(load_credential_openings(
+            credential,
+            &spec.opened_attributes(i),
+        )?);
+    }
+    if spec.holder_binding {
+        let secret = load_secret(required(
+            holder_secret_path,
+            "Holder binding requires the holder secret",
+        )?)?;
+        let commitment = holder_commitment(secret).to_string();
+        if !credentials
+            .iter()
+            .all(|credential| credential.value("holder_commitment") == Some(&commitment))
+        {
+            return Err(CredentialError::UnsatisfiedPredicate(
+                "Holder secret does not match every credential's holder commitment".to_owned(),
+            ));
+        }
+        param.push(secret.to_string());
+    }
+    param.extend(
+        signatures
+            .iter()
+            .flat_map(|signature| signature[..2].iter().cloned()),
+    );
+    param.extend(signatures.iter().map(|signature| signature[2].clone()));
+    backend.compute_witness(workspace.path(), &param)?;
+    let proof = generate_proof(backend, workspace.path(), proving_key_path)?;
+    save_json(format!("{}/proof.json", destination_path), &proof)?;
+
+    let presentation = MultiPresentation {
+        proof,
+        current_time,
+        issuer_keys,
+        disclosed: spec
+            .reveal
+            .iter()
+            .zip(&credentials)
+            .map(|(reveal, credential)| {
+                reveal
+                    .iter()
+                    .map(|&attribute| {
+                        let value = credential.value(attribute).unwrap();
+                        (attribute.to_owned(), value.to_owned())
+                    })
+                    .collect()
+            })
+            .collect(),
+    };
+    let presentation_path = format!("{}/presentation.json", destination_path);
+    save_json(&presentation_path, &presentation)?;
+    println!("Presentation saved to: {}", presentation_path);
+    Ok(())
+}
+
+// 설정(Config)의 작업 루트에서 회로를 준비하고 증명하는 프루버
+// 자격증명, 서명, 폐기 목록 등을 파일 대신 값으로 받아 프레젠테이션을 값으로 반환합니다.
+pub struct Prover {
+    config: Config,
+    backend: Box<dyn ZkBackend>,
+}
+
+impl Prover {
+    pub fn new(config: Config) -> Prover {
+        let backend = Box::new(config.backend());
+        Prover::with_backend(config, backend)
+    }
+
+    // ZokratesCli 대신 다른 백엔드(ArkBackend, MockBackend 등)로 증명
+    pub fn with_backend(config: Config, backend: Box<dyn ZkBackend>) -> Prover {
+        Prover { config, backend }
+    }
+
+    // <root>/prover 아래에 구성(spec)에 맞는 회로를 생성하고 컴파일
+    pub fn setup(&self, spec: &impl Circuit) -> Result<()> {
+        let prover_dir = self.config.prover_dir();
+        install_libraries(&prover_dir)?;
+        let circuit_dir = spec.prepare(&prover_dir)?;
+        self.backend.compile(&circuit_dir, "verify_credential.zok")
+    }
+
+    // 증명 키는 설정의 keys_dir에서 같은 구성(spec)의 것을 사용합니다.
+    pub fn prove(
+        &self,
+        credential: &Credential,
+        signature: &Signature,
+        min_age: u8,
+        spec: &CircuitSpec,
+        inputs: &ProofInputs,
+    ) -> Result<Presentation> {
+        if !spec.issuer_set {
+            self.config.check_fixed_issuer_key()?;
+        }
+        prove(
+            &*self.backend,
+            &spec.dir(self.config.prover_dir()),
+            &proving_key_path(&spec.dir(&self.config.keys_dir))?,
+            &self.config.issuer_public_key,
+            credential,
+            signature,
+            min_age,
+            spec,
+            inputs,
+        )
+    }
 }
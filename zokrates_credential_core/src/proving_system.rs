@@ -0,0 +1,180 @@
+use crate::error::{load_json, path_str, save_json, CredentialError, Result};
+use crate::proof::Proof;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+// ZoKrates가 지원하는 증명 방식, 백엔드, 곡선 중 이 프로젝트에서 선택할 수 있는 것
+pub const SCHEMES: [&str; 3] = ["g16", "gm17", "marlin"];
+pub const BACKENDS: [&str; 2] = ["ark", "bellman"];
+pub const CURVES: [&str; 2] = ["bn128", "bls12_381"];
+
+// 회로 설정과 증명에 사용하는 증명 시스템 (zokrates의 --proving-scheme, --backend, --curve)
+// 설정 시 증명 키 옆에 <proving.key>.json으로 기록하고, 증명 전에 프루버의 선택과 비교합니다.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ProvingSystem {
+    pub scheme: String,
+    pub backend: String,
+    pub curve: String,
+}
+
+// zokrates의 기본값 (Groth16, arkworks, bn128)
+impl Default for ProvingSystem {
+    fn default() -> ProvingSystem {
+        ProvingSystem {
+            scheme: "g16".to_owned(),
+            backend: "ark".to_owned(),
+            curve: "bn128".to_owned(),
+        }
+    }
+}
+
+impl ProvingSystem {
+    // 지원하지 않는 값이나 조합이면 Err
+    pub fn new(scheme: &str, backend: &str, curve: &str) -> Result<ProvingSystem> {
+        let invalid = |message: String| Err(CredentialError::InvalidInput(message));
+        if !SCHEMES.contains(&scheme) {
+            return invalid(format!("Unsupported proving scheme: {}", scheme));
+        }
+        if !BACKENDS.contains(&backend) {
+            return invalid(format!("Unsupported backend: {}", backend));
+        }
+        if !CURVES.contains(&curve) {
+            return invalid(format!("Unsupported curve: {}", curve));
+        }
+        // bellman은 Groth16만 지원합니다.
+        if backend == "bellman" && scheme != "g16" {
+            return invalid(format!("The bellman backend does not support {}", scheme));
+        }
+        // 발급자 서명(BabyJubJub EdDSA)은 bn128의 스칼라 필드 위에서 정의되므로
+        // 다른 곡선에서는 회로가 서명을 검증할 수 없습니다.
+        if curve != "bn128" {
+            return invalid(format!(
+                "Credential circuits verify BabyJubJub signatures, which require bn128, not {}",
+                curve
+            ));
+        }
+        Ok(ProvingSystem {
+            scheme: scheme.to_owned(),
+            backend: backend.to_owned(),
+            curve: curve.to_owned(),
+        })
+    }
+
+    // 회로마다 설정하지 않고 공유하는 범용 SRS에서 키를 유도하는 방식인지
+    pub fn is_universal(&self) -> bool {
+        self.scheme == "marlin"
+    }
+
+    // 증명 키의 메타데이터를 읽습니다. 메타데이터가 없는 키는 zokrates의 기본값으로 설정된 것입니다.
+    pub fn load_for_key(proving_key_path: &str) -> Result<ProvingSystem> {
+        let path = metadata_path(proving_key_path);
+        if !Path::new(&path).exists() {
+            return Ok(ProvingSystem::default());
+        }
+        load_json("proving key metadata", path)
+    }
+
+    pub fn save_for_key(&self, proving_key_path: &str) -> Result<()> {
+        save_json(metadata_path(proving_key_path), self)
+    }
+
+    // 증명 키가 이 증명 시스템으로 설정된 것인지 확인
+    pub fn check_key(&self, proving_key_path: &str) -> Result<()> {
+        let key_system = ProvingSystem::load_for_key(proving_key_path)?;
+        if &key_system != self {
+            return Err(CredentialError::InvalidInput(format!(
+                "Proving key was set up for {} but {} was requested",
+                key_system, self
+            )));
+        }
+        Ok(())
+    }
+
+    // 증명이 이 증명 시스템으로 만들어진 것인지 확인 (백엔드가 기록되지 않은 증명은 방식과 곡선만 비교)
+    pub fn check_proof(&self, proof: &Proof) -> Result<()> {
+        let backend = proof.backend.as_deref().unwrap_or(&self.backend);
+        if proof.scheme != self.scheme || proof.curve != self.curve || backend != self.backend {
+            return Err(CredentialError::InvalidInput(format!(
+                "Proof was generated with {}/{}/{} but {} was expected",
+                proof.scheme, backend, proof.curve, self
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ProvingSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.scheme, self.backend, self.curve)
+    }
+}
+
+// 증명 키의 메타데이터 경로 (예: proving.key -> proving.key.json)
+pub fn metadata_path(proving_key_path: &str) -> String {
+    format!("{}.json", proving_key_path)
+}
+
+// 범용 설정 방식에서 회로들이 공유하는 SRS 경로 (회로 디렉토리들의 상위 디렉토리, 예: ./zok/verifier)
+pub fn universal_setup_path(circuit_dir: &Path) -> Result<PathBuf> {
+    let base_dir = circuit_dir.parent().ok_or_else(|| {
+        CredentialError::InvalidInput(format!(
+            "Invalid circuit directory: {}",
+            circuit_dir.display()
+        ))
+    })?;
+    Ok(base_dir.join("universal_setup.dat"))
+}
+
+// 회로 디렉토리의 proving.key 경로
+pub fn proving_key_path(circuit_dir: &Path) -> Result<String> {
+    path_str(&circuit_dir.join("proving.key"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn proving_system_test() {
+        assert_eq!(
+            ProvingSystem::new("g16", "ark", "bn128").unwrap(),
+            ProvingSystem::default()
+        );
+        assert!(ProvingSystem::new("gm17", "ark", "bn128").is_ok());
+        assert!(ProvingSystem::new("marlin", "ark", "bn128").is_ok());
+        assert!(ProvingSystem::new("g16", "bellman", "bn128").is_ok());
+        assert!(ProvingSystem::new("gm17", "bellman", "bn128").is_err());
+        assert!(ProvingSystem::new("g16", "ark", "bls12_381").is_err());
+        assert!(ProvingSystem::new("plonk", "ark", "bn128").is_err());
+
+        let gm17 = ProvingSystem::new("gm17", "ark", "bn128").unwrap();
+        let mut proof = Proof {
+            scheme: "g16".to_owned(),
+            curve: "bn128".to_owned(),
+            backend: None,
+            proof: json!({}),
+            inputs: Vec::new(),
+        };
+        assert!(ProvingSystem::default().check_proof(&proof).is_ok());
+        assert!(gm17.check_proof(&proof).is_err());
+        proof.backend = Some("bellman".to_owned());
+        assert!(ProvingSystem::default().check_proof(&proof).is_err());
+
+        // 메타데이터가 없는 키는 기본값으로 설정된 것으로 봅니다.
+        let dir = std::env::temp_dir().join("proving_system_test");
+        fs::create_dir_all(&dir).unwrap();
+        let key_path = proving_key_path(&dir).unwrap();
+        let _ = fs::remove_file(metadata_path(&key_path));
+        assert!(ProvingSystem::default().check_key(&key_path).is_ok());
+        gm17.save_for_key(&key_path).unwrap();
+        assert!(gm17.check_key(&key_path).is_ok());
+        assert!(ProvingSystem::default().check_key(&key_path).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,422 @@
+use crate::circuit::CircuitSpec;
+use crate::credential::leaf_index;
+use crate::eddsa::{self, Point, GENERATOR, ISSUER_PUBLIC_KEY, JUBJUB_A, JUBJUB_D};
+use crate::error::{self, CredentialError};
+use crate::groth16::encode_proof;
+use crate::merkle::DEPTH;
+use crate::proof::Proof;
+use ark_bn254::{Bn254, Fr};
+use ark_crypto_primitives::crh::sha256::constraints::Sha256Gadget;
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::{Groth16, ProvingKey};
+use ark_r1cs_std::{
+    alloc::AllocVar, bits::uint8::UInt8, boolean::Boolean, eq::EqGadget, fields::fp::FpVar,
+    fields::FieldVar, R1CSVar, ToBitsGadget,
+};
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, SynthesisError,
+};
+use num_bigint::BigUint;
+use rand::rngs::OsRng;
+use std::{cmp::Ordering, str::FromStr};
+
+// verify_credential.zok과 같은 명제를 arkworks R1CS로 구성한 회로
+// 공개 입력의 순서와 값이 ZoKrates 회로와 같으므로 같은 프레젠테이션 검사를 그대로 사용할 수 있습니다.
+// 지원하는 구성: 공개 속성(reveal)과 나이 검사 방식(birth_date), 발급자 키는 eddsa::ISSUER_PUBLIC_KEY
+#[derive(Debug, Clone)]
+pub struct CredentialCircuit {
+    spec: CircuitSpec,
+    // main 함수 인자 순서의 값 (ZoKrates compute-witness 인자와 같습니다.), 설정할 때는 None
+    args: Option<Vec<Fr>>,
+}
+
+// 이 회로로 증명할 수 있는 구성인지 확인
+pub fn supports(spec: &CircuitSpec) -> bool {
+    *spec
+        == CircuitSpec {
+            reveal: spec.reveal.clone(),
+            birth_date: spec.birth_date,
+            ..CircuitSpec::default()
+        }
+        && spec
+            .reveal
+            .iter()
+            .all(|attribute| leaf_index(attribute).is_ok())
+}
+
+// 인자별 공개 여부 (main 함수 인자 순서)
+// 고정 공개 입력, 여는 리프마다 값과 머클 경로, 서명 R, S 순서입니다.
+fn public_args(spec: &CircuitSpec) -> Vec<bool> {
+    let mut layout = vec![true; spec.public_params().len()];
+    for attribute in spec.opened_attributes() {
+        layout.push(spec.reveal.contains(&attribute));
+        layout.extend([false; 2 * DEPTH]);
+    }
+    layout.extend([false; 3]);
+    layout
+}
+
+impl CredentialCircuit {
+    // 증명 키 생성에 쓰는 값이 없는 회로
+    pub fn new(spec: &CircuitSpec) -> error::Result<CredentialCircuit> {
+        if !supports(spec) {
+            return Err(CredentialError::InvalidInput(
+                "The native circuit supports only --reveal and --use-birth-date".to_owned(),
+            ));
+        }
+        Ok(CredentialCircuit {
+            spec: spec.clone(),
+            args: None,
+        })
+    }
+
+    // args: 10진수 인자 (prover가 compute-witness에 넘기는 값)
+    pub fn with_args(spec: &CircuitSpec, args: &[String]) -> error::Result<CredentialCircuit> {
+        let mut circuit = CredentialCircuit::new(spec)?;
+        if args.len() != public_args(spec).len() {
+            return Err(CredentialError::InvalidInput(
+                "Wrong number of circuit arguments".to_owned(),
+            ));
+        }
+        circuit.args = Some(
+            args.iter()
+                .map(|arg| {
+                    Fr::from_str(arg).map_err(|_| CredentialError::parse("circuit argument", arg))
+                })
+                .collect::<error::Result<_>>()?,
+        );
+        Ok(circuit)
+    }
+
+    // proof.json의 inputs가 되는 공개 입력 값
+    pub fn public_inputs(&self) -> error::Result<Vec<Fr>> {
+        let args = self
+            .args
+            .as_ref()
+            .ok_or_else(|| CredentialError::InvalidInput("Circuit has no arguments".to_owned()))?;
+        Ok(public_args(&self.spec)
+            .iter()
+            .zip(args)
+            .filter(|(public, _)| **public)
+            .map(|(_, value)| *value)
+            .collect())
+    }
+}
+
+// 128비트 미만인 값의 빅엔디언 16바이트 (ZoKrates sha256packed의 unpack128)
+fn unpack128(value: &FpVar<Fr>) -> Result<Vec<UInt8<Fr>>, SynthesisError> {
+    let bits = (0..128)
+        .map(|i| {
+            Boolean::new_witness(value.cs(), || {
+                value.value().map(|value| value.into_bigint().get_bit(i))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Boolean::le_bits_to_fp_var(&bits)?.enforce_equal(value)?;
+    Ok(bits.chunks(8).rev().map(UInt8::from_bits_le).collect())
+}
+
+fn constant_bytes(bytes: &[u8]) -> Vec<UInt8<Fr>> {
+    bytes.iter().map(|&byte| UInt8::constant(byte)).collect()
+}
+
+// 필드 원소의 빅엔디언 256비트 (ZoKrates의 unpack256)
+fn unpack256(value: &FpVar<Fr>) -> Result<Vec<UInt8<Fr>>, SynthesisError> {
+    let mut bits = value.to_bits_le()?;
+    bits.resize(256, Boolean::FALSE);
+    Ok(bits.chunks(8).rev().map(UInt8::from_bits_le).collect())
+}
+
+fn sha256(bytes: &[UInt8<Fr>]) -> Result<Vec<UInt8<Fr>>, SynthesisError> {
+    Ok(Sha256Gadget::digest(bytes)?.0)
+}
+
+// zok/merkle.zok의 merkleRoot: 리프 위치는 회로 구성에서 정해지므로 방향은 상수입니다.
+// 노드는 sha256packed 출력 두 개를 이어 붙인 32바이트로 다룹니다.
+fn merkle_root(
+    index: usize,
+    value: &FpVar<Fr>,
+    path: &[FpVar<Fr>],
+) -> Result<Vec<UInt8<Fr>>, SynthesisError> {
+    let mut preimage = constant_bytes(&[0u8; 32]);
+    preimage.extend(constant_bytes(&(index as u128).to_be_bytes()));
+    preimage.extend(unpack128(value)?);
+    let mut node = sha256(&preimage)?;
+    for (level, sibling) in path.chunks(2).enumerate() {
+        let mut sibling_bytes = unpack128(&sibling[0])?;
+        sibling_bytes.extend(unpack128(&sibling[1])?);
+        node = if (index >> level) & 1 == 1 {
+            sha256(&[sibling_bytes, node].concat())?
+        } else {
+            sha256(&[node, sibling_bytes].concat())?
+        };
+    }
+    Ok(node)
+}
+
+type PointVar = [FpVar<Fr>; 2];
+
+fn constant_point(p: &Point) -> PointVar {
+    [FpVar::constant(p[0]), FpVar::constant(p[1])]
+}
+
+// zok/eddsa.zok의 add (한쪽이 상수이면 곱셈이 줄어듭니다.)
+fn add_points(p: &PointVar, q: &PointVar) -> Result<PointVar, SynthesisError> {
+    let x1y2 = &p[0] * &q[1];
+    let y1x2 = &p[1] * &q[0];
+    let x1x2 = &p[0] * &q[0];
+    let y1y2 = &p[1] * &q[1];
+    let t = &x1x2 * &y1y2 * Fr::from(JUBJUB_D);
+    let x = (x1y2 + y1x2).mul_by_inverse(&(FpVar::one() + &t))?;
+    let y = (y1y2 - x1x2 * Fr::from(JUBJUB_A)).mul_by_inverse(&(FpVar::one() - t))?;
+    Ok([x, y])
+}
+
+// 상수 점의 스칼라 곱 (bits는 리틀엔디언)
+fn scalar_mult(bits: &[Boolean<Fr>], base: &Point) -> Result<PointVar, SynthesisError> {
+    let mut accumulated = constant_point(&[Fr::from(0u8), Fr::from(1u8)]);
+    let mut doubled = *base;
+    for bit in bits {
+        let candidate = add_points(&accumulated, &constant_point(&doubled))?;
+        accumulated = [
+            bit.select(&candidate[0], &accumulated[0])?,
+            bit.select(&candidate[1], &accumulated[1])?,
+        ];
+        doubled = eddsa::add(&doubled, &doubled);
+    }
+    Ok(accumulated)
+}
+
+// zok/eddsa.zok의 verifyEddsa (발급자 공개키 A는 상수)
+fn verify_eddsa(
+    r: &PointVar,
+    s: &FpVar<Fr>,
+    public_key: &Point,
+    root: &[UInt8<Fr>],
+) -> Result<(), SynthesisError> {
+    // R이 곡선 위에 있고 작은 부분군에 속하지 않는지 검사
+    let (xx, yy) = (r[0].square()?, r[1].square()?);
+    (&xx * Fr::from(JUBJUB_A) + &yy)
+        .enforce_equal(&(FpVar::one() + xx * yy * Fr::from(JUBJUB_D)))?;
+    let mut r8 = add_points(r, r)?;
+    r8 = add_points(&r8, &r8)?;
+    r8 = add_points(&r8, &r8)?;
+    r8[0]
+        .is_zero()?
+        .and(&r8[1].is_one()?)?
+        .enforce_equal(&Boolean::FALSE)?;
+
+    // hRAM = sha256(R.x || A.x || M0 || M1), M0, M1은 루트의 각 필드를 256비트로 풀어 쓴 값
+    let mut preimage = unpack256(&r[0])?;
+    let mut ax = BigUint::from(public_key[0]).to_bytes_be();
+    ax.splice(0..0, vec![0u8; 32 - ax.len()]);
+    preimage.extend(constant_bytes(&ax));
+    for half in root.chunks(16) {
+        preimage.extend(constant_bytes(&[0u8; 16]));
+        preimage.extend_from_slice(half);
+    }
+    let h_ram = sha256(&preimage)?
+        .iter()
+        .rev()
+        .map(|byte| byte.to_bits_le())
+        .collect::<Result<Vec<_>, _>>()?
+        .concat();
+
+    // S * G == R + hRAM * A
+    let lhs = scalar_mult(&s.to_bits_le()?, &eddsa::constant_point(GENERATOR))?;
+    let rhs = add_points(r, &scalar_mult(&h_ram, public_key)?)?;
+    lhs[0].enforce_equal(&rhs[0])?;
+    lhs[1].enforce_equal(&rhs[1])
+}
+
+impl ConstraintSynthesizer<Fr> for CredentialCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let layout = public_args(&self.spec);
+        let mut next = 0;
+        let mut alloc = || {
+            let i = next;
+            next += 1;
+            let value = || {
+                self.args
+                    .as_ref()
+                    .map(|args| args[i])
+                    .ok_or(SynthesisError::AssignmentMissing)
+            };
+            if layout[i] {
+                FpVar::new_input(cs.clone(), value)
+            } else {
+                FpVar::new_witness(cs.clone(), value)
+            }
+        };
+
+        let min_age = alloc()?;
+        let current_time = alloc()?;
+        let today = self.spec.birth_date.then(&mut alloc).transpose()?;
+
+        // 여는 리프들이 모두 같은 머클 루트로 이어지는지 검사
+        let mut root: Option<Vec<UInt8<Fr>>> = None;
+        let mut opened = Vec::new();
+        for attribute in self.spec.opened_attributes() {
+            let value = alloc()?;
+            let path = (0..2 * DEPTH)
+                .map(|_| alloc())
+                .collect::<Result<Vec<_>, _>>()?;
+            // supports가 속성을 확인하므로 실패하지 않습니다.
+            let index = leaf_index(attribute).map_err(|_| SynthesisError::Unsatisfiable)?;
+            let node = merkle_root(index, &value, &path)?;
+            match &root {
+                Some(root) => node.enforce_equal(root)?,
+                None => root = Some(node),
+            }
+            opened.push((attribute, value));
+        }
+        let value_of = |name: &str| {
+            opened
+                .iter()
+                .find(|(attribute, _)| *attribute == name)
+                .map(|(_, value)| value.clone())
+                .unwrap()
+        };
+
+        // Eddsa 서명 검증
+        let r = [alloc()?, alloc()?];
+        let s = alloc()?;
+        verify_eddsa(
+            &r,
+            &s,
+            &eddsa::constant_point(ISSUER_PUBLIC_KEY),
+            &root.unwrap(),
+        )?;
+
+        if let Some(today) = today {
+            // 4 * 경과 일수 >= 1461 * 최소 나이 (zok 회로와 같은 검사)
+            let birth_date = value_of("birth_date");
+            today.enforce_cmp(&birth_date, Ordering::Greater, true)?;
+            ((today - birth_date) * Fr::from(4u8)).enforce_cmp(
+                &(min_age * Fr::from(1461u16)),
+                Ordering::Greater,
+                true,
+            )?;
+        } else {
+            value_of("age").enforce_cmp(&min_age, Ordering::Greater, true)?;
+        }
+
+        // 자격증명이 증명 시각에 아직 만료되지 않았는지 검사
+        value_of("exp").enforce_cmp(&current_time, Ordering::Greater, false)
+    }
+}
+
+// 회로 구성의 증명 키 생성 (검증 키는 pk.vk)
+pub fn setup(spec: &CircuitSpec) -> error::Result<ProvingKey<Bn254>> {
+    Groth16::<Bn254>::generate_random_parameters_with_reduction(
+        CredentialCircuit::new(spec)?,
+        &mut OsRng,
+    )
+    .map_err(synthesis_error)
+}
+
+// main 함수 인자 순서의 값으로 proof.json 형식의 증명 생성
+// Groth16 증명자는 제약을 검사하지 않으므로, 만족하지 않는 인자는 증명 전에 거절합니다.
+pub fn prove(
+    spec: &CircuitSpec,
+    proving_key: &ProvingKey<Bn254>,
+    args: &[String],
+) -> error::Result<Proof> {
+    let circuit = CredentialCircuit::with_args(spec, args)?;
+    let cs = ConstraintSystem::new_ref();
+    circuit
+        .clone()
+        .generate_constraints(cs.clone())
+        .map_err(synthesis_error)?;
+    if !cs.is_satisfied().map_err(synthesis_error)? {
+        return Err(CredentialError::UnsatisfiedPredicate(
+            "Credential does not satisfy the circuit".to_owned(),
+        ));
+    }
+    let inputs = circuit.public_inputs()?;
+    let proof =
+        Groth16::<Bn254>::create_random_proof_with_reduction(circuit, proving_key, &mut OsRng)
+            .map_err(synthesis_error)?;
+    Ok(encode_proof(&proof, &inputs))
+}
+
+fn synthesis_error(error: SynthesisError) -> CredentialError {
+    CredentialError::InvalidInput(format!("Circuit synthesis failed: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eddsa::{sign, ISSUER_SECRET_KEY};
+    use crate::merkle::{message_words, MerkleTree};
+
+    // 나이 30, 만료 시각 2000000000인 자격증명과 발급자 서명으로 만든 회로 인자
+    fn credential_args(spec: &CircuitSpec, min_age: u8) -> Vec<String> {
+        let mut values = vec![None; 16];
+        values[leaf_index("age").unwrap()] = Some(30);
+        values[leaf_index("exp").unwrap()] = Some(2_000_000_000);
+        values[leaf_index("name").unwrap()] = Some(1234);
+        let tree = MerkleTree::new(&values).unwrap();
+        let secret_key = BigUint::from_str(ISSUER_SECRET_KEY).unwrap();
+        let signature = sign(&secret_key, &message_words(&tree.root()));
+
+        let mut args = vec![min_age.to_string(), "1700000000".to_owned()];
+        for attribute in spec.opened_attributes() {
+            let index = leaf_index(attribute).unwrap();
+            args.push(values[index].unwrap().to_string());
+            for sibling in tree.path(index) {
+                args.extend(sibling.iter().map(u128::to_string));
+            }
+        }
+        args.extend(signature.r.iter().map(|c| c.to_string()));
+        args.push(signature.s.to_string());
+        args
+    }
+
+    fn is_satisfied(spec: &CircuitSpec, args: &[String]) -> bool {
+        let cs = ConstraintSystem::new_ref();
+        CredentialCircuit::with_args(spec, args)
+            .unwrap()
+            .generate_constraints(cs.clone())
+            .unwrap();
+        cs.is_satisfied().unwrap()
+    }
+
+    #[test]
+    fn credential_circuit_test() {
+        let spec = CircuitSpec::new(&["name".to_owned()], false).unwrap();
+        assert!(supports(&spec));
+        assert!(!supports(&CircuitSpec {
+            holder_binding: true,
+            ..spec.clone()
+        }));
+        assert!(CredentialCircuit::new(&CircuitSpec {
+            reveal: vec!["unknown"],
+            ..spec.clone()
+        })
+        .is_err());
+        assert!(CredentialCircuit::new(&spec)
+            .unwrap()
+            .public_inputs()
+            .is_err());
+
+        let args = credential_args(&spec, 20);
+        assert!(is_satisfied(&spec, &args));
+        let circuit = CredentialCircuit::with_args(&spec, &args).unwrap();
+        assert_eq!(
+            circuit.public_inputs().unwrap(),
+            [20u64, 1_700_000_000, 1234].map(Fr::from)
+        );
+
+        // 최소 나이를 넘지 못하거나, 공개 속성 값을 바꾸거나, 서명이 다르면 만족하지 않음
+        assert!(!is_satisfied(&spec, &credential_args(&spec, 31)));
+        // 여는 리프는 age, exp, name 순서이므로 name의 값은 고정 공개 입력 2개와 리프 2개 뒤에 옵니다.
+        let mut forged = args.clone();
+        forged[2 + 2 * (1 + 2 * DEPTH)] = "1235".to_owned();
+        assert!(!is_satisfied(&spec, &forged));
+        let mut forged = args;
+        let last = forged.len() - 1;
+        forged[last] = "1".to_owned();
+        assert!(!is_satisfied(&spec, &forged));
+    }
+}
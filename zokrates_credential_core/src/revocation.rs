@@ -0,0 +1,250 @@
+use crate::eddsa::{self, Signature};
+use crate::error::{load_json, save_json, CredentialError, Result};
+use crate::merkle::{leaf_hash, message_words, node_hash, Node, EMPTY_LEAF};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+// 폐기 레지스트리 희소 머클 트리의 깊이 (zok/merkle.zok의 REVOCATION_DEPTH와 같아야 합니다.)
+// 발급자는 자격증명마다 2^20개 중 하나의 폐기 인덱스를 부여합니다.
+pub const REVOCATION_DEPTH: usize = 20;
+pub const REVOCATION_CAPACITY: u64 = 1 << REVOCATION_DEPTH;
+
+// 폐기된 인덱스의 리프 값 (폐기되지 않은 인덱스는 빈 리프)
+const REVOKED: u128 = 1;
+
+// 폐기된 인덱스만 리프로 가지는 희소 머클 트리
+// 폐기되지 않았음은 해당 인덱스의 리프가 빈 리프라는 경로로 증명합니다.
+pub struct RevocationTree {
+    // layers[0]은 리프, 마지막 층은 루트 (값이 있는 노드만 저장)
+    layers: Vec<BTreeMap<u64, Node>>,
+    // empty[level]: 높이가 level인 빈 서브트리의 루트
+    empty: Vec<Node>,
+}
+
+impl RevocationTree {
+    pub fn new(revoked: &BTreeSet<u64>) -> Result<RevocationTree> {
+        if let Some(index) = revoked.range(REVOCATION_CAPACITY..).next() {
+            return Err(CredentialError::InvalidInput(format!(
+                "Revocation index out of range: {}",
+                index
+            )));
+        }
+
+        let mut empty = vec![EMPTY_LEAF];
+        for level in 0..REVOCATION_DEPTH {
+            empty.push(node_hash(&empty[level], &empty[level]));
+        }
+
+        let leaves = revoked
+            .iter()
+            .map(|&index| (index, leaf_hash(index as usize, REVOKED)))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut layers = vec![leaves];
+        for level in 0..REVOCATION_DEPTH {
+            let layer = &layers[level];
+            let parents = layer
+                .keys()
+                .map(|index| index >> 1)
+                .collect::<BTreeSet<_>>();
+            let next = parents
+                .into_iter()
+                .map(|parent| {
+                    let child = |index| *layer.get(&index).unwrap_or(&empty[level]);
+                    (
+                        parent,
+                        node_hash(&child(parent << 1), &child(parent << 1 | 1)),
+                    )
+                })
+                .collect();
+            layers.push(next);
+        }
+        Ok(RevocationTree { layers, empty })
+    }
+
+    pub fn root(&self) -> Node {
+        *self.layers[REVOCATION_DEPTH]
+            .get(&0)
+            .unwrap_or(&self.empty[REVOCATION_DEPTH])
+    }
+
+    pub fn is_revoked(&self, index: u64) -> bool {
+        self.layers[0].contains_key(&index)
+    }
+
+    // 리프에서 루트까지의 형제 노드 목록 (아래층부터)
+    pub fn path(&self, index: u64) -> Vec<Node> {
+        (0..REVOCATION_DEPTH)
+            .map(|level| {
+                let sibling = (index >> level) ^ 1;
+                *self.layers[level]
+                    .get(&sibling)
+                    .unwrap_or(&self.empty[level])
+            })
+            .collect()
+    }
+}
+
+// 발급자 측 레지스트리 (./zok/issuer/registry.json)
+// 발급한 자격증명의 폐기 인덱스와 폐기된 인덱스 목록을 기록합니다.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Registry {
+    pub next_index: u64,
+    pub credentials: BTreeMap<String, u64>,
+    pub revoked: BTreeSet<u64>,
+}
+
+impl Registry {
+    // 레지스트리 파일이 없으면 빈 레지스트리
+    pub fn load(registry_path: &str) -> Result<Registry> {
+        if !Path::new(registry_path).exists() {
+            return Ok(Registry::default());
+        }
+        load_json("registry", registry_path)
+    }
+
+    pub fn save(&self, registry_path: &str) -> Result<()> {
+        save_json(registry_path, self)
+    }
+
+    // 새 자격증명에 폐기 인덱스 부여
+    // 같은 id로 다시 발급하면 이전에 발급한 자격증명은 폐기됩니다.
+    pub fn register(&mut self, credential_id: &str) -> Result<u64> {
+        let index = self.next_index;
+        if index >= REVOCATION_CAPACITY {
+            return Err(CredentialError::InvalidInput(
+                "Revocation registry is full".to_owned(),
+            ));
+        }
+        self.next_index += 1;
+        if let Some(previous) = self.credentials.insert(credential_id.to_owned(), index) {
+            self.revoked.insert(previous);
+        }
+        Ok(index)
+    }
+
+    pub fn revoke(&mut self, credential_id: &str) -> Result<u64> {
+        let index = *self.credentials.get(credential_id).ok_or_else(|| {
+            CredentialError::InvalidInput(format!("Unknown credential: {}", credential_id))
+        })?;
+        self.revoked.insert(index);
+        Ok(index)
+    }
+
+    pub fn tree(&self) -> Result<RevocationTree> {
+        RevocationTree::new(&self.revoked)
+    }
+}
+
+// 발급자가 공개하는 폐기 목록: 폐기된 인덱스와 서명된 루트
+// 서명은 루트에만 적용되므로, 검증자는 최신 목록을 발급자로부터 직접 받아야 합니다.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RevocationList {
+    pub revoked: BTreeSet<u64>,
+    // 루트의 두 128비트 값 (10진수)
+    pub root: [String; 2],
+    pub published: DateTime<Utc>,
+    // 루트에 대한 발급자 서명 "R.x R.y S"
+    pub signature: String,
+}
+
+impl RevocationList {
+    pub fn load(revocation_list_path: &str) -> Result<RevocationList> {
+        load_json("revocation list", revocation_list_path)
+    }
+
+    pub fn tree(&self) -> Result<RevocationTree> {
+        RevocationTree::new(&self.revoked)
+    }
+
+    // 루트가 폐기 목록과 일치하고 발급자(issuer_public_key)의 서명이 유효한지 확인
+    // 범위를 벗어난 인덱스가 있거나 공개키가 곡선 위의 점이 아니면 거부합니다.
+    pub fn verify(&self, issuer_public_key: &[String; 2]) -> bool {
+        let Ok(tree) = self.tree() else {
+            return false;
+        };
+        let root = tree.root();
+        if self.root != root.map(|value| value.to_string()) {
+            return false;
+        }
+        match (
+            Signature::parse(&self.signature),
+            eddsa::point([&issuer_public_key[0], &issuer_public_key[1]]),
+        ) {
+            (Some(signature), Ok(public_key)) => {
+                eddsa::verify(&public_key, &signature, &message_words(&root))
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eddsa::ISSUER_PUBLIC_KEY;
+
+    // 빈 리프에서 경로를 따라 루트 계산 (zok/merkle.zok의 nonRevokedRoot)
+    fn non_revoked_root(index: u64, path: &[Node]) -> Node {
+        let mut node = EMPTY_LEAF;
+        for (level, sibling) in path.iter().enumerate() {
+            node = if (index >> level) & 1 == 1 {
+                node_hash(sibling, &node)
+            } else {
+                node_hash(&node, sibling)
+            };
+        }
+        node
+    }
+
+    #[test]
+    fn non_revocation_path_test() {
+        let mut registry = Registry::default();
+        for id in ["a", "b", "c"] {
+            registry.register(id).unwrap();
+        }
+        assert_eq!(registry.revoke("b").unwrap(), 1);
+        assert!(registry.revoke("d").is_err());
+        let tree = registry.tree().unwrap();
+
+        assert_ne!(
+            tree.root(),
+            RevocationTree::new(&BTreeSet::new()).unwrap().root()
+        );
+        assert!(tree.is_revoked(1));
+        for index in [0, 2, REVOCATION_CAPACITY - 1] {
+            assert_eq!(non_revoked_root(index, &tree.path(index)), tree.root());
+        }
+        assert_ne!(non_revoked_root(1, &tree.path(1)), tree.root());
+    }
+
+    #[test]
+    fn reissue_test() {
+        let mut registry = Registry::default();
+        assert_eq!(registry.register("a").unwrap(), 0);
+        assert_eq!(registry.register("a").unwrap(), 1);
+        assert_eq!(registry.revoked, BTreeSet::from([0]));
+    }
+
+    // 범위를 벗어난 인덱스가 있는 폐기 목록은 패닉 없이 거부
+    #[test]
+    fn out_of_range_test() {
+        let revoked = BTreeSet::from([1, REVOCATION_CAPACITY]);
+        assert!(matches!(
+            RevocationTree::new(&revoked),
+            Err(CredentialError::InvalidInput(_))
+        ));
+        let list = RevocationList {
+            revoked,
+            root: Default::default(),
+            published: Utc::now(),
+            signature: String::new(),
+        };
+        assert!(list.tree().is_err());
+        assert!(!list.verify(&ISSUER_PUBLIC_KEY.map(str::to_owned)));
+    }
+}
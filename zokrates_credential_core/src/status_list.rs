@@ -0,0 +1,203 @@
+use crate::credential::CredentialStatus;
+use crate::eddsa::{self, Signature};
+use crate::error::{load_json, CredentialError, Result};
+use crate::merkle::{message_words, Node};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeSet,
+    io::{Read, Write},
+};
+
+// 발급자가 공개하는 상태 목록의 기본 주소 (자격증명의 credential_status가 가리킵니다.)
+// 발급 기관별 주소는 profile::IssuerProfile의 status_list_url로 정합니다.
+pub const STATUS_LIST_URL: &str = "https://infosec.chungnam.ac.kr/credentials/status/1";
+// 상태 목록의 최소 길이 (비트): 목록 크기로 자격증명 수를 짐작할 수 없도록 16KB 이상으로 만듭니다.
+pub const MIN_STATUS_LIST_LENGTH: usize = 131072;
+
+// 자격증명에 넣을 상태 항목: 폐기 인덱스를 상태 목록의 위치로 사용합니다.
+pub fn status_entry(status_list_url: &str, index: u64) -> CredentialStatus {
+    CredentialStatus {
+        id: format!("{}#{}", status_list_url, index),
+        status_type: "BitstringStatusListEntry".to_owned(),
+        status_purpose: "revocation".to_owned(),
+        status_list_index: index.to_string(),
+        status_list_credential: status_list_url.to_owned(),
+    }
+}
+
+// 비트열(인덱스 0이 첫 바이트의 최상위 비트)을 GZIP으로 압축하고 multibase base64url("u" 접두사)로 인코딩
+pub fn encode_bitstring(revoked: &BTreeSet<u64>, length: usize) -> Result<String> {
+    if let Some(index) = revoked.range(length as u64..).next() {
+        return Err(CredentialError::InvalidInput(format!(
+            "Status index out of range: {}",
+            index
+        )));
+    }
+    let mut bitstring = vec![0u8; length.div_ceil(8)];
+    for &index in revoked {
+        let index = index as usize;
+        bitstring[index / 8] |= 0x80 >> (index % 8);
+    }
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(&bitstring)
+        .expect("Failed to compress status list");
+    let compressed = encoder.finish().expect("Failed to compress status list");
+    Ok(format!("u{}", URL_SAFE_NO_PAD.encode(compressed)))
+}
+
+pub fn decode_bitstring(encoded_list: &str) -> Option<Vec<u8>> {
+    let compressed = URL_SAFE_NO_PAD
+        .decode(encoded_list.strip_prefix('u')?)
+        .ok()?;
+    let mut bitstring = Vec::new();
+    GzDecoder::new(&compressed[..])
+        .read_to_end(&mut bitstring)
+        .ok()?;
+    Some(bitstring)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StatusListSubject {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub subject_type: String,
+    pub status_purpose: String,
+    pub encoded_list: String,
+}
+
+// 발급자가 서명해 공개하는 상태 목록 (W3C Bitstring Status List 형식을 따름)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StatusList {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub list_type: Vec<String>,
+    pub issuer: String,
+    pub valid_from: DateTime<Utc>,
+    pub credential_subject: StatusListSubject,
+    // signature를 제외한 JSON의 다이제스트에 대한 발급자 서명 "R.x R.y S"
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub signature: String,
+}
+
+impl StatusList {
+    // 서명 전의 상태 목록 (목록 길이는 발급한 인덱스 수에 맞춰 늘어납니다.)
+    pub fn new(
+        status_list_url: &str,
+        issuer: &str,
+        revoked: &BTreeSet<u64>,
+        issued: u64,
+    ) -> Result<StatusList> {
+        let length = MIN_STATUS_LIST_LENGTH.max((issued as usize).div_ceil(8) * 8);
+        Ok(StatusList {
+            id: status_list_url.to_owned(),
+            list_type: vec![
+                "VerifiableCredential".to_owned(),
+                "BitstringStatusListCredential".to_owned(),
+            ],
+            issuer: issuer.to_owned(),
+            valid_from: Utc::now(),
+            credential_subject: StatusListSubject {
+                id: format!("{}#list", status_list_url),
+                subject_type: "BitstringStatusList".to_owned(),
+                status_purpose: "revocation".to_owned(),
+                encoded_list: encode_bitstring(revoked, length)?,
+            },
+            signature: String::new(),
+        })
+    }
+
+    pub fn load(status_list_path: &str) -> Result<StatusList> {
+        load_json("status list", status_list_path)
+    }
+
+    // 서명 대상: signature를 뺀 JSON의 SHA-256을 128비트 두 개로 나눈 값
+    pub fn digest(&self) -> Node {
+        let unsigned = StatusList {
+            signature: String::new(),
+            ..self.clone()
+        };
+        let json = serde_json::to_vec(&unsigned).expect("Failed to serialize status list");
+        let digest = Sha256::digest(json);
+        let mut high = [0u8; 16];
+        let mut low = [0u8; 16];
+        high.copy_from_slice(&digest[..16]);
+        low.copy_from_slice(&digest[16..]);
+        [u128::from_be_bytes(high), u128::from_be_bytes(low)]
+    }
+
+    // 발급자(issuer_public_key)의 서명이 유효한지 확인
+    pub fn verify(&self, issuer_public_key: &[String; 2]) -> bool {
+        match (
+            Signature::parse(&self.signature),
+            eddsa::point([&issuer_public_key[0], &issuer_public_key[1]]),
+        ) {
+            (Some(signature), Ok(public_key)) => {
+                eddsa::verify(&public_key, &signature, &message_words(&self.digest()))
+            }
+            _ => false,
+        }
+    }
+
+    // 인덱스의 상태 비트 (목록 밖이거나 목록을 해석할 수 없으면 None)
+    pub fn status(&self, index: u64) -> Option<bool> {
+        let bitstring = decode_bitstring(&self.credential_subject.encoded_list)?;
+        let byte = bitstring.get(usize::try_from(index / 8).ok()?)?;
+        Some(byte & (0x80 >> (index % 8)) != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eddsa::ISSUER_PUBLIC_KEY;
+
+    #[test]
+    fn status_test() {
+        let revoked = BTreeSet::from([0, 9, 131071]);
+        let list = StatusList::new(
+            STATUS_LIST_URL,
+            "https://infosec.chungnam.ac.kr",
+            &revoked,
+            3,
+        )
+        .unwrap();
+        assert_eq!(
+            status_entry(STATUS_LIST_URL, 9).status_list_credential,
+            list.id
+        );
+        assert_eq!(
+            decode_bitstring(&list.credential_subject.encoded_list)
+                .unwrap()
+                .len(),
+            MIN_STATUS_LIST_LENGTH / 8
+        );
+        assert_eq!(list.status(0), Some(true));
+        assert_eq!(list.status(1), Some(false));
+        assert_eq!(list.status(9), Some(true));
+        assert_eq!(list.status(131071), Some(true));
+        assert_eq!(list.status(131072), None);
+
+        // JSON으로 저장했다가 읽어도 다이제스트는 같습니다.
+        let json = serde_json::to_string(&list).unwrap();
+        let loaded: StatusList = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.digest(), list.digest());
+        assert!(!loaded.verify(&ISSUER_PUBLIC_KEY.map(str::to_owned)));
+    }
+
+    // 목록 길이를 벗어난 인덱스는 패닉 없이 거부
+    #[test]
+    fn out_of_range_test() {
+        let revoked = BTreeSet::from([MIN_STATUS_LIST_LENGTH as u64]);
+        assert!(matches!(
+            encode_bitstring(&revoked, MIN_STATUS_LIST_LENGTH),
+            Err(CredentialError::InvalidInput(_))
+        ));
+        assert!(StatusList::new(STATUS_LIST_URL, "issuer", &revoked, 3).is_err());
+        assert!(StatusList::new(STATUS_LIST_URL, "issuer", &revoked, 131073).is_ok());
+    }
+}
@@ -1,53 +1,1156 @@
-use std::fs;
-use std::process::Command;
+use crate::backend::ZkBackend;
+use crate::challenge::{verifier_field, ChallengeStore};
+use crate::circuit::{Circuit, CircuitSpec, MultiCircuitSpec};
+use crate::config::{install_libraries, Config};
+use crate::credential::{day_number, CredentialStatus};
+use crate::eddsa::ISSUER_PUBLIC_KEY;
+use crate::error::{
+    copy_file, create_dir, path_str, save_json, write_file, CredentialError, Result,
+};
+use crate::groth16::{self, VerificationKey};
+use crate::issuer_set::TrustedIssuers;
+use crate::nullifier::{parse_nullifier, scope_field, NullifierStore};
+use crate::proof::{
+    encode_field, encode_public_input, text_field, MultiPresentation, Presentation, Proof,
+};
+use crate::proving_system::{metadata_path, proving_key_path, ProvingSystem};
+use crate::revocation::RevocationList;
+use crate::status_list::StatusList;
+use crate::workspace::Workspace;
+use ark_bn254::Bn254;
+use ark_groth16::{prepare_verifying_key, Groth16};
+use chrono::Utc;
+use std::path::{Path, PathBuf};
 
-pub fn get_proving_key(destination_path: &str) {
+// 검증자가 발행한 미사용 챌린지 목록
+pub const CHALLENGE_STORE_PATH: &str = "./zok/verifier/challenges.json";
+// 검증자가 받아들인 널리파이어 목록
+pub const NULLIFIER_STORE_PATH: &str = "./zok/verifier/nullifiers.json";
+// 범용 설정 방식(marlin)에서 모든 회로가 공유하는 SRS (proving_system::universal_setup_path)
+pub const UNIVERSAL_SETUP_PATH: &str = "./zok/verifier/universal_setup.dat";
+
+// 회로 구성에 따라 프레젠테이션 검사에 필요한 검증자 측 상태
+#[derive(Debug, Default)]
+pub struct VerifierContext<'a> {
+    // spec.revocation: 발급자로부터 받은 최신 폐기 목록
+    pub revocation_list: Option<&'a RevocationList>,
+    // 폐기 목록에 서명한 발급자 공개키 (None이면 eddsa::ISSUER_PUBLIC_KEY)
+    pub issuer_public_key: Option<&'a [String; 2]>,
+    // spec.issuer_set: 검증자가 신뢰하는 발급자 집합
+    pub trusted_issuers: Option<&'a TrustedIssuers>,
+    // spec.challenge: 발행한 미사용 챌린지 (통과하면 사용한 챌린지를 제거)
+    pub challenges: Option<&'a mut ChallengeStore>,
+    // spec.nullifier: 검증자가 정한 스코프와 받아들인 널리파이어 (통과하면 기록)
+    pub scope: Option<&'a str>,
+    pub nullifiers: Option<&'a mut NullifierStore>,
+    // spec.pseudonym: 이 검증자의 도메인 (가명은 이 도메인에 대한 것이어야 합니다.)
+    pub verifier_domain: Option<&'a str>,
+}
+
+pub fn get_proving_key(destination_path: &str, spec: &impl Circuit) -> Result<()> {
+    let circuit_dir = spec.dir("./zok/verifier");
     // `proving.key` 파일의 현재 경로
-    let source_path = "./zok/verifier/proving.key";
+    let source_path = circuit_dir.join("proving.key");
     // `proving.key` 파일을 이동할 목적지 경로
     let destination_path = &format!("{}/proving.key", destination_path);
 
     // 파일 복사
-    fs::copy(source_path, destination_path).expect("Failed to copy proving.key file. Setup First");
+    copy_file(source_path, destination_path)?;
     println!("File copied to: {}", destination_path); // 복사된 파일 위치 로깅
+
+    // 증명 시스템 메타데이터 (proving.key.json)
+    copy_file(
+        metadata_path(&proving_key_path(&circuit_dir)?),
+        metadata_path(destination_path),
+    )
+}
+
+// spec: 공개 속성, 나이 검사 방식 등 회로 구성
+pub fn setup(backend: &dyn ZkBackend, spec: &impl Circuit) -> Result<()> {
+    setup_circuit(backend, spec, Path::new("./zok/verifier"))
+}
+
+// base_dir 아래 회로 구성별 디렉토리에 회로를 생성하고 키와 컨트랙트를 만듭니다.
+fn setup_circuit(backend: &dyn ZkBackend, spec: &impl Circuit, base_dir: &Path) -> Result<()> {
+    let circuit_dir = spec.prepare(base_dir)?;
+    backend.compile(&circuit_dir, "verify_credential.zok")?;
+    backend.setup(&circuit_dir)?;
+    // 프루버가 같은 증명 시스템을 사용하는지 확인할 수 있도록 증명 키 옆에 기록
+    backend
+        .proving_system()
+        .save_for_key(&proving_key_path(&circuit_dir)?)?;
+    export_contracts(backend, spec, &circuit_dir)
+}
+
+// 범용 SRS를 한 번 생성합니다. 이후 회로 구성이 바뀌어도 새 세레모니 없이 이 SRS에서 키를 유도합니다.
+// 기존 SRS로 유도한 키가 무효가 되지 않도록 이미 있으면 덮어쓰지 않습니다.
+pub fn universal_setup(backend: &dyn ZkBackend, size: u32) -> Result<()> {
+    let path = Path::new(UNIVERSAL_SETUP_PATH);
+    if path.exists() {
+        return Err(CredentialError::InvalidInput(format!(
+            "Universal setup already exists: {}",
+            UNIVERSAL_SETUP_PATH
+        )));
+    }
+    backend.universal_setup(path, size)
+}
+
+// 저장된 범용 SRS로 회로의 키를 필요할 때 유도합니다.
+// 같은 증명 시스템의 키가 이미 있으면 그대로 사용하고 false를 반환합니다.
+pub fn derive_keys(backend: &dyn ZkBackend, spec: &impl Circuit) -> Result<bool> {
+    derive_keys_in(backend, spec, Path::new("./zok/verifier"))
 }
 
-pub fn setup() {
-    // `zokrates compile` 명령어 실행
-    let compile_status = Command::new("zokrates")
-        .current_dir("./zok/verifier") // 작업 디렉토리 설정
-        .arg("compile")
-        .arg("-i")
-        .arg("verify_credential.zok")
-        .status() // 명령어 실행
-        .expect("Failed to execute zokrates compile");
-    assert!(compile_status.success()); // 컴파일 성공 확인
-
-    // `zokrates setup` 명령어 실행
-    let compile_status = Command::new("zokrates")
-        .current_dir("./zok/verifier") // 작업 디렉토리 설정
-        .arg("setup")
-        .status() // 명령어 실행
-        .expect("Failed to execute zokrates setup");
-    assert!(compile_status.success()); // setup 성공 확인
-
-    // `zokrates export-verifier` 명령어 실행
+// base_dir 아래 회로 구성별 디렉토리에 키를 유도합니다.
+fn derive_keys_in(backend: &dyn ZkBackend, spec: &impl Circuit, base_dir: &Path) -> Result<bool> {
+    let system = backend.proving_system();
+    if !system.is_universal() {
+        return Err(CredentialError::InvalidInput(format!(
+            "{} keys cannot be derived from a universal setup",
+            system.scheme
+        )));
+    }
+    let key_path = proving_key_path(&spec.dir(base_dir))?;
+    if Path::new(&key_path).exists() && ProvingSystem::load_for_key(&key_path)? == system {
+        return Ok(false);
+    }
+    setup_circuit(backend, spec, base_dir)?;
+    Ok(true)
+}
+
+// 설정한 검증 키로 컨트랙트 생성
+pub(crate) fn export_contracts(
+    backend: &dyn ZkBackend,
+    spec: &impl Circuit,
+    circuit_dir: &Path,
+) -> Result<()> {
     // verifier.sol 컨트렉트 생성
-    let compile_status = Command::new("zokrates")
-        .current_dir("./zok/verifier") // 작업 디렉토리 설정
-        .arg("export-verifier")
-        .status() // 명령어 실행
-        .expect("Failed to execute zokrates setup");
-    assert!(compile_status.success()); // setup 성공 확인
+    backend.export_verifier(circuit_dir)?;
+
+    // 널리파이어 회로 등은 래퍼 컨트랙트를 함께 생성
+    if let Some(contract) = spec.contract()? {
+        write_file(circuit_dir.join("credential_verifier.sol"), contract)?;
+    }
+    Ok(())
 }
 
-pub fn get_verify_contract(destination_path: &str) {
+pub fn get_verify_contract(destination_path: &str, spec: &impl Circuit) -> Result<()> {
+    let circuit_dir = spec.dir("./zok/verifier");
     // `verifier.sol` 파일의 현재 경로
-    let source_path = "./zok/verifier/verifier.sol";
+    let source_path = circuit_dir.join("verifier.sol");
     // `verifier.sol` 파일을 이동할 목적지 경로
-    let destination_path = &format!("{}/verifier.sol", destination_path);
+    let contract_path = &format!("{}/verifier.sol", destination_path);
 
     // 파일 복사
-    fs::copy(source_path, destination_path).expect("Failed to copy verifier.sol file.");
-    println!("File copied to: {}", destination_path); // 복사된 파일 위치 로깅
+    copy_file(source_path, contract_path)?;
+    println!("File copied to: {}", contract_path); // 복사된 파일 위치 로깅
+
+    // 래퍼 컨트랙트 (verifier.sol을 임포트하므로 같은 위치에 둡니다.)
+    if spec.contract()?.is_some() {
+        let source_path = circuit_dir.join("credential_verifier.sol");
+        let wrapper_path = &format!("{}/credential_verifier.sol", destination_path);
+        copy_file(source_path, wrapper_path)?;
+        println!("File copied to: {}", wrapper_path);
+    }
+    Ok(())
+}
+
+// 체인에 컨트랙트를 배포하지 않고 proof.json을 직접 검증 (BN254 위의 Groth16 페어링 검사)
+// verification_key는 `zokrates setup`이 생성한 verification.key입니다.
+// 증명이나 키의 형식이 잘못되었으면 Err, 페어링 검사에 실패하면 Ok(false)를 반환합니다.
+pub fn verify_proof(proof: &Proof, verification_key: &VerificationKey) -> Result<bool> {
+    groth16::check_scheme(&proof.scheme, &proof.curve)?;
+    let vk = groth16::parse_verification_key(verification_key)?;
+    if proof.inputs.len() + 1 != vk.gamma_abc_g1.len() {
+        return Err(CredentialError::InvalidInput(format!(
+            "Expected {} public inputs, got {}",
+            vk.gamma_abc_g1.len() - 1,
+            proof.inputs.len()
+        )));
+    }
+    let inputs = proof
+        .inputs
+        .iter()
+        .map(|input| groth16::parse_fr(input))
+        .collect::<Result<Vec<_>>>()?;
+    let groth16_proof = groth16::parse_proof(&proof.proof)?;
+    Groth16::<Bn254>::verify_proof(&prepare_verifying_key(&vk), &groth16_proof, &inputs)
+        .map_err(|error| CredentialError::parse("verification key", error))
+}
+
+// 검증 키(verification.key)로 증명을 검증
+// 증명이 백엔드의 증명 시스템으로 만들어진 것이 아니거나 키가 없으면 Err, 검증에 실패하면 Ok(false)를 반환합니다.
+// 백엔드는 파일에서 증명을 읽으므로 임시 작업 디렉토리에 써서 검증합니다.
+pub fn verify(
+    backend: &dyn ZkBackend,
+    verification_key_path: &Path,
+    proof: &Proof,
+) -> Result<bool> {
+    backend.proving_system().check_proof(proof)?;
+    if !verification_key_path.exists() {
+        return Err(CredentialError::MissingSetup(format!(
+            "{} not found. Setup first",
+            verification_key_path.display()
+        )));
+    }
+    let workspace = Workspace::temp()?;
+    let proof_path = workspace.path().join("proof.json");
+    save_json(&proof_path, proof)?;
+    backend.verify(&path_str(verification_key_path)?, &path_str(&proof_path)?)
+}
+
+// 증명의 공개 입력에 기록된 최소 나이가 검증자가 요구한 값과 같은지 확인
+// 최소 나이는 verify_credential.zok의 첫 번째 공개 입력입니다.
+pub fn check_min_age(proof_path: &str, min_age: u8) -> Result<bool> {
+    let proof = Proof::load(proof_path)?;
+    Ok(proof.inputs.first() == Some(&encode_public_input(min_age.into())))
+}
+
+// 프레젠테이션의 증명을 검증하고 공개 입력 값이 증명의 공개 입력과 일치하는지 확인
+// - 증명은 구성(spec)의 검증 키(verification_key_path)로 검증합니다.
+// - spec은 검증자가 설정한 회로 구성이며, 공개된 속성 목록이 spec.reveal과 같아야 합니다.
+// - 증명 시각은 검증자 시계와 tolerance(초) 이내로 차이 나야 합니다.
+// - 생년월일 검사의 기준 날짜는 검증자의 오늘과 하루 이내로 차이 나야 합니다.
+// - 폐기 레지스트리 검사는 검증자가 받은 최신 폐기 목록(서명 확인)의 루트를 기준으로 해야 합니다.
+// - 발급자 집합 검사는 검증자가 신뢰하는 발급자 집합의 루트를 기준으로 해야 합니다.
+// - 챌린지는 검증자가 발행한 미사용 챌린지여야 하며, 통과하면 사용한 것으로 처리합니다.
+// - 널리파이어는 검증자의 스코프에서 처음 사용된 것이어야 하며, 통과하면 기록합니다.
+// - 가명은 이 검증자의 도메인에 대한 것이어야 합니다.
+// 챌린지와 널리파이어는 증명을 검증한 뒤에만 기록하므로 위조한 증명으로 다른 홀더의 것을 소모할 수 없습니다.
+// 키나 증명을 읽을 수 없거나 증명 시스템이 다르면 Err를 반환합니다.
+pub fn check_presentation(
+    backend: &dyn ZkBackend,
+    verification_key_path: &Path,
+    presentation: &Presentation,
+    min_age: u8,
+    spec: &CircuitSpec,
+    tolerance: i64,
+    context: &mut VerifierContext,
+) -> Result<bool> {
+    if !matches_presentation(presentation, min_age, spec, tolerance, context)
+        || !verify(backend, verification_key_path, &presentation.proof)?
+    {
+        return Ok(false);
+    }
+    if let (Some(challenge), Some(store)) = (&presentation.challenge, &mut context.challenges) {
+        store.consume(challenge);
+    }
+    if let (Some(scope), Some(nullifier), Some(store)) = (
+        &presentation.scope,
+        presentation.nullifier.as_deref().and_then(parse_nullifier),
+        &mut context.nullifiers,
+    ) {
+        store.insert(scope, nullifier);
+    }
+    Ok(true)
+}
+
+// 검증자 측 상태는 바꾸지 않고 프레젠테이션의 값을 검증자 측 상태, 증명의 공개 입력과 대조
+fn matches_presentation(
+    presentation: &Presentation,
+    min_age: u8,
+    spec: &CircuitSpec,
+    tolerance: i64,
+    context: &VerifierContext,
+) -> bool {
+    let disclosed = presentation.disclosed.keys().map(String::as_str);
+    if !disclosed.eq(spec.reveal.iter().copied()) {
+        return false;
+    }
+
+    let now = Utc::now();
+    if presentation.min_age != min_age
+        || differs(now.timestamp(), presentation.current_time, tolerance)
+    {
+        return false;
+    }
+    match presentation.today {
+        Some(today) if spec.birth_date => {
+            if differs(day_number(now.date_naive()), today, 1) {
+                return false;
+            }
+        }
+        None if !spec.birth_date => {}
+        _ => return false,
+    }
+    let builtin_key = ISSUER_PUBLIC_KEY.map(str::to_owned);
+    let issuer_public_key = context.issuer_public_key.unwrap_or(&builtin_key);
+    match (&presentation.revocation_root, context.revocation_list) {
+        (Some(root), Some(list)) if spec.revocation => {
+            if root != &list.root || !list.verify(issuer_public_key) {
+                return false;
+            }
+        }
+        (None, _) if !spec.revocation => {}
+        _ => return false,
+    }
+    match (&presentation.issuer_root, context.trusted_issuers) {
+        (Some(root), Some(issuers)) if spec.issuer_set => {
+            if root != &issuers.root || !issuers.verify() {
+                return false;
+            }
+        }
+        (None, _) if !spec.issuer_set => {}
+        _ => return false,
+    }
+    match (&presentation.challenge, &context.challenges) {
+        (Some(challenge), Some(store)) if spec.challenge => {
+            if !store.is_outstanding(challenge) {
+                return false;
+            }
+        }
+        (None, _) if !spec.challenge => {}
+        _ => return false,
+    }
+    match (
+        &presentation.scope,
+        &presentation.nullifier,
+        context.scope,
+        &context.nullifiers,
+    ) {
+        (Some(scope), Some(nullifier), Some(expected), Some(store)) if spec.nullifier => {
+            let unused = parse_nullifier(nullifier)
+                .is_some_and(|nullifier| !store.contains(scope, nullifier));
+            if scope != expected || !unused {
+                return false;
+            }
+        }
+        (None, None, _, _) if !spec.nullifier => {}
+        _ => return false,
+    }
+    match (&presentation.verifier_domain, context.verifier_domain) {
+        (Some(domain), Some(expected)) if spec.pseudonym => {
+            if domain != expected || presentation.pseudonym.is_none() {
+                return false;
+            }
+        }
+        (None, _) if !spec.pseudonym && presentation.pseudonym.is_none() => {}
+        _ => return false,
+    }
+
+    expected_inputs(presentation, min_age, spec)
+        .is_some_and(|expected| presentation.proof.inputs == expected)
+}
+
+// 검증자의 값과 프레젠테이션의 값이 tolerance보다 더 차이 나는지 확인
+// 프레젠테이션의 값은 홀더가 정하므로 i64 범위 끝의 값이어도 넘치지 않게 비교합니다.
+fn differs(expected: i64, value: i64, tolerance: i64) -> bool {
+    expected.abs_diff(value) > u64::try_from(tolerance).unwrap_or(0)
+}
+
+// spec.public_inputs 순서대로 기대하는 공개 입력 값 (프레젠테이션에 값이 없으면 None)
+fn expected_inputs(
+    presentation: &Presentation,
+    min_age: u8,
+    spec: &CircuitSpec,
+) -> Option<Vec<String>> {
+    let mut expected = Vec::new();
+    for name in spec.public_inputs() {
+        let value = match name.as_str() {
+            "min_age" => Some(min_age.into()),
+            "current_time" => u128::try_from(presentation.current_time).ok(),
+            "today" => presentation
+                .today
+                .and_then(|today| u128::try_from(today).ok()),
+            "revocation_root[0]" | "revocation_root[1]" => {
+                let i = usize::from(name.ends_with("[1]"));
+                presentation
+                    .revocation_root
+                    .as_ref()
+                    .and_then(|root| root[i].parse::<u128>().ok())
+            }
+            "issuer_root[0]" | "issuer_root[1]" => {
+                let i = usize::from(name.ends_with("[1]"));
+                presentation
+                    .issuer_root
+                    .as_ref()
+                    .and_then(|root| root[i].parse::<u128>().ok())
+            }
+            "verifier_id" => presentation
+                .challenge
+                .as_ref()
+                .map(|challenge| verifier_field(&challenge.verifier_id)),
+            "nonce" => presentation
+                .challenge
+                .as_ref()
+                .and_then(|challenge| challenge.nonce.parse::<u128>().ok()),
+            "scope" => presentation.scope.as_deref().map(scope_field),
+            "nullifier" => presentation.nullifier.as_deref().and_then(parse_nullifier),
+            "verifier_domain" => presentation.verifier_domain.as_deref().map(text_field),
+            "pseudonym" => presentation
+                .pseudonym
+                .as_ref()
+                .and_then(|pseudonym| pseudonym.parse::<u128>().ok()),
+            "challenge_response" => presentation
+                .challenge_response
+                .as_ref()
+                .and_then(|response| response.parse::<u128>().ok()),
+            attribute => presentation
+                .disclosed
+                .get(attribute)
+                .and_then(|value| value.parse::<u128>().ok()),
+        };
+        expected.push(encode_public_input(value?));
+    }
+    Some(expected)
+}
+
+// 디렉토리의 발급자 공개키 파일들로 신뢰하는 발급자 집합을 만들어
+// <destination_path>/trusted_issuers.json으로 저장 (홀더에게도 공개합니다.)
+pub fn build_trusted_issuers(keys_dir: &str, destination_path: &str) -> Result<TrustedIssuers> {
+    let issuers = TrustedIssuers::from_dir(keys_dir)?;
+    let issuers_path = format!("{}/trusted_issuers.json", destination_path);
+    issuers.save(&issuers_path)?;
+    println!(
+        "Trusted issuers ({} keys) saved to: {}",
+        issuers.keys.len(),
+        issuers_path
+    );
+    Ok(issuers)
+}
+
+// 여러 자격증명 프레젠테이션의 증명을 검증하고 공개 입력 값이 증명의 공개 입력과 일치하는지 확인
+// - 증명은 구성(spec)의 검증 키(verification_key_path)로 검증합니다.
+// - 자격증명별로 공개된 속성 목록이 spec.reveal과 같아야 합니다.
+// - 모든 자격증명의 발급자 공개키가 검증자가 신뢰하는 키(trusted_issuers)여야 합니다.
+// - 증명 시각은 검증자 시계와 tolerance(초) 이내로 차이 나야 합니다.
+// 키나 증명을 읽을 수 없거나 증명 시스템이 다르면 Err를 반환합니다.
+pub fn check_multi_presentation(
+    backend: &dyn ZkBackend,
+    verification_key_path: &Path,
+    presentation: &MultiPresentation,
+    spec: &MultiCircuitSpec,
+    trusted_issuers: &[[String; 2]],
+    tolerance: i64,
+) -> Result<bool> {
+    if !matches_multi_presentation(presentation, spec, trusted_issuers, tolerance) {
+        return Ok(false);
+    }
+    verify(backend, verification_key_path, &presentation.proof)
+}
+
+fn matches_multi_presentation(
+    presentation: &MultiPresentation,
+    spec: &MultiCircuitSpec,
+    trusted_issuers: &[[String; 2]],
+    tolerance: i64,
+) -> bool {
+    if presentation.disclosed.len() != spec.count()
+        || presentation.issuer_keys.len() != spec.count()
+    {
+        return false;
+    }
+    for (disclosed, reveal) in presentation.disclosed.iter().zip(&spec.reveal) {
+        if disclosed.len() != reveal.len()
+            || !reveal
+                .iter()
+                .all(|attribute| disclosed.contains_key(*attribute))
+        {
+            return false;
+        }
+    }
+    if !presentation
+        .issuer_keys
+        .iter()
+        .all(|key| trusted_issuers.contains(key))
+    {
+        return false;
+    }
+    if differs(Utc::now().timestamp(), presentation.current_time, tolerance) {
+        return false;
+    }
+    expected_multi_inputs(presentation, spec)
+        .is_some_and(|expected| presentation.proof.inputs == expected)
+}
+
+// spec.public_inputs 순서: 증명 시각, 발급자 공개키, 자격증명별 공개 속성 (리프 순서)
+fn expected_multi_inputs(
+    presentation: &MultiPresentation,
+    spec: &MultiCircuitSpec,
+) -> Option<Vec<String>> {
+    let mut expected = vec![u128::try_from(presentation.current_time)
+        .ok()
+        .map(encode_public_input)];
+    expected.extend(
+        presentation
+            .issuer_keys
+            .iter()
+            .flatten()
+            .map(|value| encode_field(value)),
+    );
+    for (disclosed, reveal) in presentation.disclosed.iter().zip(&spec.reveal) {
+        expected.extend(reveal.iter().map(|&attribute| {
+            disclosed
+                .get(attribute)
+                .and_then(|value| value.parse::<u128>().ok())
+                .map(encode_public_input)
+        }));
+    }
+    expected.into_iter().collect()
+}
+
+// 검증자 챌린지를 발행하여 <destination_path>/challenge.json으로 저장
+// 미사용 챌린지는 ./zok/verifier/challenges.json에 기록됩니다.
+pub fn create_challenge(verifier_id: &str, lifetime: i64, destination_path: &str) -> Result<()> {
+    let mut store = ChallengeStore::load(CHALLENGE_STORE_PATH)?;
+    let challenge = store.issue(verifier_id, lifetime);
+    store.save(CHALLENGE_STORE_PATH)?;
+
+    let challenge_path = format!("{}/challenge.json", destination_path);
+    save_json(&challenge_path, &challenge)?;
+    println!("Challenge saved to: {}", challenge_path);
+    Ok(())
+}
+
+// 회로 밖에서 자격증명의 상태 항목을 발급자가 공개한 상태 목록과 대조
+// 목록에 발급자(issuer_public_key)의 서명이 유효하고, 항목이 이 목록을 가리키며, 해당 비트가 꺼져 있어야 통과합니다.
+pub fn check_status(
+    status: &CredentialStatus,
+    status_list: &StatusList,
+    issuer_public_key: &[String; 2],
+) -> bool {
+    if !status_list.verify(issuer_public_key)
+        || status.status_list_credential != status_list.id
+        || status.status_purpose != status_list.credential_subject.status_purpose
+    {
+        return false;
+    }
+    match status.status_list_index.parse::<u64>() {
+        Ok(index) => status_list.status(index) == Some(false),
+        Err(_) => false,
+    }
+}
+
+// 설정(Config)의 keys_dir에서 회로와 키를 관리하고 증명을 값으로 받아 검증하는 검증자
+pub struct Verifier {
+    config: Config,
+    backend: Box<dyn ZkBackend>,
+}
+
+impl Verifier {
+    pub fn new(config: Config) -> Verifier {
+        let backend = Box::new(config.backend());
+        Verifier::with_backend(config, backend)
+    }
+
+    // ZokratesCli 대신 다른 백엔드(ArkBackend, MockBackend 등)로 설정하고 검증
+    pub fn with_backend(config: Config, backend: Box<dyn ZkBackend>) -> Verifier {
+        Verifier { config, backend }
+    }
+
+    // <keys_dir> 아래에 구성(spec)에 맞는 회로, 키(proving.key, verification.key), 컨트랙트를 생성
+    pub fn setup(&self, spec: &impl Circuit) -> Result<()> {
+        install_libraries(&self.config.keys_dir)?;
+        setup_circuit(&*self.backend, spec, &self.config.keys_dir)
+    }
+
+    // 범용 SRS를 <keys_dir>/universal_setup.dat에 한 번 생성 (이미 있으면 덮어쓰지 않습니다.)
+    pub fn universal_setup(&self, size: u32) -> Result<()> {
+        let path = self.universal_setup_path();
+        if path.exists() {
+            return Err(CredentialError::InvalidInput(format!(
+                "Universal setup already exists: {}",
+                path.display()
+            )));
+        }
+        create_dir(&self.config.keys_dir)?;
+        self.backend.universal_setup(&path, size)
+    }
+
+    pub fn universal_setup_path(&self) -> PathBuf {
+        self.config.keys_dir.join("universal_setup.dat")
+    }
+
+    // <keys_dir>의 범용 SRS로 구성(spec)의 키를 필요할 때 유도 (derive_keys)
+    pub fn derive_keys(&self, spec: &impl Circuit) -> Result<bool> {
+        install_libraries(&self.config.keys_dir)?;
+        derive_keys_in(&*self.backend, spec, &self.config.keys_dir)
+    }
+
+    // 구성(spec)의 검증 키로 증명을 검증
+    pub fn verify(&self, spec: &impl Circuit, proof: &Proof) -> Result<bool> {
+        verify(
+            &*self.backend,
+            &spec.dir(&self.config.keys_dir).join("verification.key"),
+            proof,
+        )
+    }
+
+    // 구성(spec)의 검증 키로 프레젠테이션을 검사 (check_presentation)
+    // 폐기 목록은 context에 발급자 공개키가 없으면 설정의 발급자 공개키로 확인합니다.
+    pub fn check_presentation<'a>(
+        &'a self,
+        presentation: &Presentation,
+        min_age: u8,
+        spec: &CircuitSpec,
+        tolerance: i64,
+        context: &mut VerifierContext<'a>,
+    ) -> Result<bool> {
+        context
+            .issuer_public_key
+            .get_or_insert(&self.config.issuer_public_key);
+        check_presentation(
+            &*self.backend,
+            &spec.dir(&self.config.keys_dir).join("verification.key"),
+            presentation,
+            min_age,
+            spec,
+            tolerance,
+            context,
+        )
+    }
+
+    // 설정의 발급자 공개키로 서명한 상태 목록과 자격증명의 상태 항목을 대조 (check_status)
+    pub fn check_status(&self, status: &CredentialStatus, status_list: &StatusList) -> bool {
+        check_status(status, status_list, &self.config.issuer_public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{ArkBackend, MockBackend, ZokratesCli};
+    use crate::did;
+    use crate::eddsa::{self, derive_public_key, sign, ISSUER_SECRET_KEY};
+    use crate::groth16::{encode_hex, encode_proof, encode_verification_key};
+    use crate::issuer::{CredentialRequest, Issuer};
+    use crate::profile::IssuerProfile;
+    use crate::prover::{ProofInputs, Prover};
+    use crate::proving_system::ProvingSystem;
+    use ark_bn254::Fr;
+    use ark_relations::lc;
+    use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use num_bigint::BigUint;
+    use serde_json::{json, Value};
+    use std::{collections::BTreeMap, fs, str::FromStr};
+
+    // x * y = z (z는 공개 입력)
+    struct MulCircuit {
+        x: Fr,
+        y: Fr,
+    }
+
+    impl ConstraintSynthesizer<Fr> for MulCircuit {
+        fn generate_constraints(
+            self,
+            cs: ConstraintSystemRef<Fr>,
+        ) -> std::result::Result<(), SynthesisError> {
+            let z = cs.new_input_variable(|| Ok(self.x * self.y))?;
+            let x = cs.new_witness_variable(|| Ok(self.x))?;
+            let y = cs.new_witness_variable(|| Ok(self.y))?;
+            cs.enforce_constraint(lc!() + x, lc!() + y, lc!() + z)
+        }
+    }
+
+    #[test]
+    fn verify_proof_test() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let circuit = || MulCircuit {
+            x: Fr::from(3u8),
+            y: Fr::from(11u8),
+        };
+        let pk = Groth16::<Bn254>::generate_random_parameters_with_reduction(circuit(), &mut rng)
+            .unwrap();
+        let proof =
+            Groth16::<Bn254>::create_random_proof_with_reduction(circuit(), &pk, &mut rng).unwrap();
+
+        let verification_key = encode_verification_key(&pk.vk);
+        let mut proof = encode_proof(&proof, &[Fr::from(33u8)]);
+        assert!(verify_proof(&proof, &verification_key).unwrap());
+        // ZokratesCli도 Groth16(bn128) 증명은 zokrates 없이 검증
+        let key_path = std::env::temp_dir().join("verify_proof_test.key");
+        save_json(&key_path, &verification_key).unwrap();
+        assert!(verify(&ZokratesCli::default(), &key_path, &proof).unwrap());
+        fs::remove_file(&key_path).unwrap();
+        assert!(matches!(
+            verify(&ZokratesCli::default(), &key_path, &proof),
+            Err(CredentialError::MissingSetup(_))
+        ));
+
+        // ArkBackend는 zokrates 없이 같은 검증 키로 verifier.sol 생성
+        let backend = ArkBackend::new(&CircuitSpec::default()).unwrap();
+        let circuit_dir = Workspace::temp().unwrap();
+        assert!(matches!(
+            backend.export_verifier(circuit_dir.path()),
+            Err(CredentialError::MissingSetup(_))
+        ));
+        save_json(
+            circuit_dir.path().join("verification.key"),
+            &verification_key,
+        )
+        .unwrap();
+        backend.export_verifier(circuit_dir.path()).unwrap();
+        let contract = fs::read_to_string(circuit_dir.path().join("verifier.sol")).unwrap();
+        assert!(contract.contains("function verifyTx(Proof memory proof, uint[1] memory input)"));
+        assert!(contract.contains(&format!(
+            "vk.gamma_abc[1] = Pairing.G1Point(uint256({}), uint256({}));",
+            verification_key.gamma_abc[1][0], verification_key.gamma_abc[1][1]
+        )));
+        assert!(contract.contains(&format!(
+            "[uint256({}), uint256({})]",
+            verification_key.beta[0][0], verification_key.beta[0][1]
+        )));
+
+        proof.inputs = vec![encode_hex(Fr::from(34u8))];
+        assert!(!verify_proof(&proof, &verification_key).unwrap());
+        proof.inputs = vec![];
+        assert!(verify_proof(&proof, &verification_key).is_err());
+        proof.inputs = vec!["0x".to_owned() + &"f".repeat(64)];
+        assert!(verify_proof(&proof, &verification_key).is_err());
+        proof.scheme = "gm17".to_owned();
+        assert!(verify_proof(&proof, &verification_key).is_err());
+    }
+
+    // ZoKrates 없이 검증자 설정부터 증명 생성, 검증까지의 흐름
+    // 임시 작업 루트를 사용하므로 현재 디렉토리에 아무것도 남기지 않습니다.
+    #[test]
+    fn mock_flow_test() {
+        let root = Workspace::temp().unwrap();
+        let system = ProvingSystem::new("gm17", "ark", "bn128").unwrap();
+        let mut config = Config::new(root.path());
+        config.proving_system = system.clone();
+        config.issuer_secret_key = Some(BigUint::from_str(ISSUER_SECRET_KEY).unwrap());
+        let backend = MockBackend::with_system(system.clone());
+        let issuer = Issuer::new(config.clone(), IssuerProfile::default()).unwrap();
+        let prover = Prover::with_backend(config.clone(), Box::new(backend.clone()));
+        let verifier = Verifier::with_backend(config.clone(), Box::new(backend.clone()));
+        let spec = CircuitSpec::new(&["age".to_owned()], false).unwrap();
+
+        verifier.setup(&spec).unwrap();
+        prover.setup(&spec).unwrap();
+
+        let subject_id = crate::holder::holder_did(11);
+        let request = CredentialRequest {
+            subject_id: &subject_id,
+            name: "Aristotle",
+            age: 21,
+            birth_date: None,
+            student_number: "202100001",
+            department: "Logic",
+            holder_commitment: None,
+        };
+        let (credential, signature) = issuer.issue("mock-flow", &request).unwrap();
+
+        // 발급자 키로 검증되지 않는 서명은 회로에 넣기 전에 거부
+        let forged = sign(&BigUint::from_str(ISSUER_SECRET_KEY).unwrap(), &[0u32; 16]);
+        assert!(matches!(
+            prover.prove(&credential, &forged, 20, &spec, &ProofInputs::default()),
+            Err(CredentialError::InvalidSignature(_))
+        ));
+        let presentation = prover
+            .prove(&credential, &signature, 20, &spec, &ProofInputs::default())
+            .unwrap();
+
+        assert!(verifier.verify(&spec, &presentation.proof).unwrap());
+        assert_eq!(presentation.disclosed["age"], "21");
+        assert_eq!(presentation.proof.inputs[0], "20");
+        // 증명 키와 증명에 기록된 증명 시스템
+        assert_eq!(presentation.proof.scheme, "gm17");
+        assert_eq!(presentation.proof.backend.as_deref(), Some("ark"));
+        let pk_path = proving_key_path(&spec.dir(&config.keys_dir)).unwrap();
+        assert!(system.check_key(&pk_path).is_ok());
+        assert!(ProvingSystem::default().check_key(&pk_path).is_err());
+        assert_eq!(
+            backend.calls(),
+            [
+                "compile verify_credential.zok",
+                "setup",
+                "export-verifier",
+                "compile verify_credential.zok",
+                "compute-witness 23",
+                "generate-proof",
+                "verify"
+            ]
+        );
+    }
+
+    // 현재 디렉토리와 build.rs 없이 설정의 작업 루트에서 발급, 증명, 검증
+    #[test]
+    fn config_flow_test() {
+        let root = std::env::temp_dir().join("config_flow_test");
+        let mut config = Config::new(&root);
+        config.proving_system = ProvingSystem::new("gm17", "ark", "bn128").unwrap();
+        config.issuer_secret_key = Some(BigUint::from_str(ISSUER_SECRET_KEY).unwrap());
+        let backend = || Box::new(MockBackend::with_system(config.proving_system.clone()));
+        let issuer = Issuer::new(config.clone(), IssuerProfile::default()).unwrap();
+        let prover = Prover::with_backend(config.clone(), backend());
+        let verifier = Verifier::with_backend(config.clone(), backend());
+        let spec = CircuitSpec::new(&["age".to_owned()], false).unwrap();
+
+        let subject_id = crate::holder::holder_did(12);
+        let request = CredentialRequest {
+            subject_id: &subject_id,
+            name: "Plato",
+            age: 30,
+            birth_date: None,
+            student_number: "202100002",
+            department: "Philosophy",
+            holder_commitment: None,
+        };
+        let (credential, signature) = issuer.issue("config-flow", &request).unwrap();
+        verifier.setup(&spec).unwrap();
+        prover.setup(&spec).unwrap();
+        let presentation = prover
+            .prove(&credential, &signature, 20, &spec, &ProofInputs::default())
+            .unwrap();
+        assert_eq!(presentation.disclosed["age"], "30");
+        assert_eq!(presentation.proof.inputs[0], "20");
+        assert!(verifier.verify(&spec, &presentation.proof).unwrap());
+        // MockBackend의 증명은 모든 인자를 공개 입력으로 기록하므로 최소 나이가 다른 경우만 확인
+        assert!(!verifier
+            .check_presentation(
+                &presentation,
+                21,
+                &spec,
+                60,
+                &mut VerifierContext::default()
+            )
+            .unwrap());
+        // 다른 증명 시스템의 증명은 검증하기 전에 거부
+        let mut proof = presentation.proof.clone();
+        proof.scheme = "g16".to_owned();
+        assert!(verifier.verify(&spec, &proof).is_err());
+
+        let index = issuer.revoke("config-flow").unwrap();
+        let list = issuer.revocation_list().unwrap();
+        assert!(list.revoked.contains(&index));
+        assert!(list.verify(&config.issuer_public_key));
+        // 기본 작업 디렉토리(./zok)는 사용하지 않습니다.
+        assert!(!Path::new("./zok/issuer/config-flow").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    // 내장 키가 아닌 설정의 발급자 키로 발급자 DID를 만들고 폐기 목록과 상태 목록을 확인
+    #[test]
+    fn issuer_key_flow_test() {
+        let root = std::env::temp_dir().join("issuer_key_flow_test");
+        let secret_key = BigUint::from(12345u32);
+        let mut config = Config::new(&root);
+        config.issuer_public_key =
+            derive_public_key(&secret_key).map(|value| BigUint::from(value).to_string());
+        config.issuer_secret_key = Some(secret_key);
+        config.issuer_set = true;
+        let issuer = Issuer::new(config.clone(), IssuerProfile::default()).unwrap();
+        let verifier = Verifier::new(config.clone());
+        let builtin_key = ISSUER_PUBLIC_KEY.map(str::to_owned);
+
+        let subject_id = crate::holder::holder_did(13);
+        let request = CredentialRequest {
+            subject_id: &subject_id,
+            name: "Zeno",
+            age: 30,
+            birth_date: None,
+            student_number: "202100003",
+            department: "Philosophy",
+            holder_commitment: None,
+        };
+        let (credential, _) = issuer.issue("issuer-key-flow", &request).unwrap();
+        let key = &config.issuer_public_key;
+        let issuer_did = did::did_key(&eddsa::point([&key[0], &key[1]]).unwrap());
+        assert_eq!(credential.claim.issuer.id, issuer_did);
+        assert_ne!(credential.claim.issuer.id, did::issuer_did());
+
+        let status_list = issuer.status_list().unwrap();
+        assert_eq!(status_list.issuer, issuer_did);
+        assert!(status_list.verify(&config.issuer_public_key));
+        assert!(!status_list.verify(&builtin_key));
+        let status = &credential.claim.credential_status;
+        assert!(verifier.check_status(status, &status_list));
+        assert!(!check_status(status, &status_list, &builtin_key));
+
+        issuer.revoke("issuer-key-flow").unwrap();
+        let list = issuer.revocation_list().unwrap();
+        assert!(list.verify(&config.issuer_public_key));
+        assert!(!list.verify(&builtin_key));
+        let status_list = issuer.status_list().unwrap();
+        assert!(!verifier.check_status(status, &status_list));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    fn empty_presentation() -> Presentation {
+        Presentation {
+            proof: Proof {
+                scheme: String::new(),
+                curve: String::new(),
+                backend: None,
+                proof: Value::Null,
+                inputs: Vec::new(),
+            },
+            min_age: 20,
+            current_time: Utc::now().timestamp(),
+            today: None,
+            revocation_root: None,
+            issuer_root: None,
+            challenge: None,
+            challenge_response: None,
+            scope: None,
+            nullifier: None,
+            verifier_domain: None,
+            pseudonym: None,
+            disclosed: BTreeMap::new(),
+        }
+    }
+
+    // root 아래에 구성(spec)의 회로를 설정하고 inputs를 공개 입력으로 하는 증명과 검증 키 경로를 반환
+    fn mock_proof(
+        backend: &MockBackend,
+        root: &Path,
+        spec: &impl Circuit,
+        inputs: &[String],
+    ) -> (Proof, PathBuf) {
+        let circuit_dir = spec.prepare(root).unwrap();
+        backend
+            .compile(&circuit_dir, "verify_credential.zok")
+            .unwrap();
+        backend.setup(&circuit_dir).unwrap();
+        backend.compute_witness(&circuit_dir, inputs).unwrap();
+        let proof_path = path_str(&circuit_dir.join("proof.json")).unwrap();
+        backend
+            .generate_proof(
+                &circuit_dir,
+                &proving_key_path(&circuit_dir).unwrap(),
+                &proof_path,
+            )
+            .unwrap();
+        (
+            Proof::load(&proof_path).unwrap(),
+            circuit_dir.join("verification.key"),
+        )
+    }
+
+    fn prove_presentation(
+        backend: &MockBackend,
+        root: &Path,
+        spec: &CircuitSpec,
+        presentation: &mut Presentation,
+    ) -> PathBuf {
+        let inputs = expected_inputs(presentation, presentation.min_age, spec).unwrap();
+        let (proof, key_path) = mock_proof(backend, root, spec, &inputs);
+        presentation.proof = proof;
+        key_path
+    }
+
+    // 범위 끝의 시각이나 날짜는 넘치지 않고 거절
+    #[test]
+    fn time_tolerance_test() {
+        let context = VerifierContext::default();
+        let spec = CircuitSpec::default();
+        for current_time in [i64::MIN, i64::MAX] {
+            let presentation = Presentation {
+                current_time,
+                ..empty_presentation()
+            };
+            assert!(!matches_presentation(
+                &presentation,
+                20,
+                &spec,
+                60,
+                &context
+            ));
+        }
+        let spec = CircuitSpec::new(&[], true).unwrap();
+        let presentation = Presentation {
+            today: Some(i64::MIN),
+            ..empty_presentation()
+        };
+        assert!(!matches_presentation(
+            &presentation,
+            20,
+            &spec,
+            60,
+            &context
+        ));
+        assert!(!differs(0, 60, 60));
+        assert!(differs(0, -61, 60));
+        assert!(differs(0, 1, -1));
+    }
+
+    // 위조한 증명으로는 널리파이어를 소모할 수 없습니다.
+    #[test]
+    fn nullifier_check_test() {
+        let backend = MockBackend::with_system(ProvingSystem::new("gm17", "ark", "bn128").unwrap());
+        let root = std::env::temp_dir().join("nullifier_check_test");
+        let spec = CircuitSpec {
+            holder_binding: true,
+            nullifier: true,
+            ..CircuitSpec::default()
+        };
+        let mut presentation = Presentation {
+            scope: Some("vote".to_owned()),
+            nullifier: Some("12345".to_owned()),
+            ..empty_presentation()
+        };
+        let key_path = prove_presentation(&backend, &root, &spec, &mut presentation);
+        let mut store = NullifierStore::default();
+        let check = |presentation: &Presentation, store: &mut NullifierStore| {
+            let mut context = VerifierContext {
+                scope: Some("vote"),
+                nullifiers: Some(store),
+                ..VerifierContext::default()
+            };
+            check_presentation(
+                &backend,
+                &key_path,
+                presentation,
+                20,
+                &spec,
+                60,
+                &mut context,
+            )
+            .unwrap()
+        };
+
+        let mut forged = presentation.clone();
+        forged.proof.proof = json!({ "digest": "forged" });
+        assert!(!check(&forged, &mut store));
+        assert!(!store.contains("vote", 12345));
+        assert!(check(&presentation, &mut store));
+        assert!(store.contains("vote", 12345));
+        assert!(!check(&presentation, &mut store));
+
+        // 같은 널리파이어를 다른 표현으로 다시 내도 거절하며, 새로 기록하지 않음
+        for replayed in ["012345", "+12345"] {
+            let replay = Presentation {
+                nullifier: Some(replayed.to_owned()),
+                ..presentation.clone()
+            };
+            assert!(!check(&replay, &mut store));
+        }
+        assert_eq!(store.used["vote"].len(), 1);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    // 챌린지는 증명을 검증한 같은 호출에서만 사용 처리합니다.
+    #[test]
+    fn challenge_check_test() {
+        let backend = MockBackend::with_system(ProvingSystem::new("gm17", "ark", "bn128").unwrap());
+        let root = std::env::temp_dir().join("challenge_check_test");
+        let spec = CircuitSpec {
+            holder_binding: true,
+            challenge: true,
+            ..CircuitSpec::default()
+        };
+        let mut store = ChallengeStore::default();
+        let challenge = store.issue("verifier.example", 60);
+        let mut presentation = Presentation {
+            challenge: Some(challenge.clone()),
+            challenge_response: Some("678".to_owned()),
+            ..empty_presentation()
+        };
+        let key_path = prove_presentation(&backend, &root, &spec, &mut presentation);
+        let check = |presentation: &Presentation, store: &mut ChallengeStore| {
+            let mut context = VerifierContext {
+                challenges: Some(store),
+                ..VerifierContext::default()
+            };
+            check_presentation(
+                &backend,
+                &key_path,
+                presentation,
+                20,
+                &spec,
+                60,
+                &mut context,
+            )
+            .unwrap()
+        };
+
+        let mut forged = presentation.clone();
+        forged.proof.proof = json!({ "digest": "forged" });
+        assert!(!check(&forged, &mut store));
+        assert!(store.is_outstanding(&challenge));
+        assert!(check(&presentation, &mut store));
+        assert!(!store.is_outstanding(&challenge));
+        assert!(!check(&presentation, &mut store));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    // 여러 자격증명 프레젠테이션도 증명을 검증해야 통과합니다.
+    #[test]
+    fn multi_presentation_check_test() {
+        let backend = MockBackend::with_system(ProvingSystem::new("gm17", "ark", "bn128").unwrap());
+        let root = std::env::temp_dir().join("multi_presentation_check_test");
+        let reveal = vec![vec!["type".to_owned()]; 2];
+        let spec = MultiCircuitSpec::new(&reveal, &[], false).unwrap();
+        let issuer_key = ISSUER_PUBLIC_KEY.map(str::to_owned);
+        let disclosed = BTreeMap::from([("type".to_owned(), "5".to_owned())]);
+        let mut presentation = MultiPresentation {
+            proof: empty_presentation().proof,
+            current_time: Utc::now().timestamp(),
+            issuer_keys: vec![issuer_key.clone(); 2],
+            disclosed: vec![disclosed; 2],
+        };
+        let inputs = expected_multi_inputs(&presentation, &spec).unwrap();
+        let (proof, key_path) = mock_proof(&backend, &root, &spec, &inputs);
+        presentation.proof = proof;
+        let trusted_issuers = [issuer_key];
+        let check = |presentation: &MultiPresentation| {
+            check_multi_presentation(
+                &backend,
+                &key_path,
+                presentation,
+                &spec,
+                &trusted_issuers,
+                60,
+            )
+            .unwrap()
+        };
+
+        assert!(check(&presentation));
+        let mut forged = presentation.clone();
+        forged.proof.proof = json!({ "digest": "forged" });
+        assert!(!check(&forged));
+        assert!(
+            !check_multi_presentation(&backend, &key_path, &presentation, &spec, &[], 60).unwrap()
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    // 범용 SRS는 한 번만 만들고, 회로 구성마다 키는 필요할 때 유도
+    #[test]
+    fn universal_setup_test() {
+        // 현재 디렉토리 대신 임시 작업 루트를 사용하므로 다시 실행해도 SRS가 남아 있지 않습니다.
+        let root = Workspace::temp().unwrap();
+        let mut config = Config::new(root.path());
+        config.proving_system = ProvingSystem::new("marlin", "ark", "bn128").unwrap();
+        let backend = MockBackend::with_system(config.proving_system.clone());
+        let verifier = Verifier::with_backend(config.clone(), Box::new(backend.clone()));
+        let name = CircuitSpec::new(&["name".to_owned()], false).unwrap();
+        let student_number = CircuitSpec::new(&["student_number".to_owned()], true).unwrap();
+
+        assert!(matches!(
+            verifier.derive_keys(&name),
+            Err(CredentialError::MissingSetup(_))
+        ));
+        verifier.universal_setup(12).unwrap();
+        assert!(verifier.universal_setup(12).is_err());
+        assert!(verifier.derive_keys(&name).unwrap());
+        assert!(!verifier.derive_keys(&name).unwrap());
+        assert!(verifier.derive_keys(&student_number).unwrap());
+        let key_path = proving_key_path(&student_number.dir(&config.keys_dir)).unwrap();
+        assert_eq!(
+            ProvingSystem::load_for_key(&key_path).unwrap().scheme,
+            "marlin"
+        );
+        assert_eq!(
+            backend.calls(),
+            [
+                "compile verify_credential.zok",
+                "setup",
+                "universal-setup 12",
+                "compile verify_credential.zok",
+                "setup",
+                "export-verifier",
+                "compile verify_credential.zok",
+                "setup",
+                "export-verifier"
+            ]
+        );
+    }
 }
@@ -0,0 +1,117 @@
+use crate::error::{CredentialError, Result};
+use rand::Rng;
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+// 작업 디렉토리에서 참조하는 회로 디렉토리의 컴파일 결과
+const SHARED_FILES: [&str; 2] = ["out", "abi.json"];
+
+// 발급이나 증명 한 건이 쓰는 임시 작업 디렉토리
+// 증인(witness), 서명 등 작업 중에 생기는 파일은 이 디렉토리에만 쓰므로 여러 작업을 동시에 실행할 수 있습니다.
+// 회로 디렉토리의 컴파일 결과는 복사하지 않고 읽기 전용으로 참조하며, 작업이 끝나면(drop) 디렉토리를 삭제합니다.
+#[derive(Debug)]
+pub struct Workspace {
+    dir: PathBuf,
+}
+
+impl Workspace {
+    pub fn new(circuit_dir: &Path) -> Result<Workspace> {
+        let circuit_dir = circuit_dir.canonicalize().map_err(|_| {
+            CredentialError::MissingSetup(format!(
+                "{} not found. Setup first",
+                circuit_dir.display()
+            ))
+        })?;
+        // 링크에 실패해도 drop에서 디렉토리를 지우도록 먼저 만듭니다.
+        let workspace = Workspace::temp()?;
+        for name in SHARED_FILES {
+            let source = circuit_dir.join(name);
+            if source.exists() {
+                let target = workspace.dir.join(name);
+                link(&source, &target).map_err(|error| CredentialError::io(target, error))?;
+            }
+        }
+        Ok(workspace)
+    }
+
+    // 회로 디렉토리를 참조하지 않는 빈 작업 디렉토리 (검증할 증명 등을 씁니다.)
+    pub fn temp() -> Result<Workspace> {
+        // 이름이 겹치면 create_dir가 실패하므로 다른 작업의 디렉토리를 재사용하지 않습니다.
+        let dir = loop {
+            let name = format!(
+                "zokrates_credential_{:016x}",
+                rand::thread_rng().gen::<u64>()
+            );
+            let dir = env::temp_dir().join(name);
+            match fs::create_dir(&dir) {
+                Ok(()) => break dir,
+                Err(error) if error.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(error) => return Err(CredentialError::io(dir, error)),
+            }
+        };
+        Ok(Workspace { dir })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[cfg(unix)]
+fn link(source: &Path, target: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(source, target)
+}
+
+#[cfg(not(unix))]
+fn link(source: &Path, target: &Path) -> io::Result<()> {
+    fs::copy(source, target).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn workspace_test() {
+        let circuit_dir = env::temp_dir().join("workspace_test");
+        fs::create_dir_all(&circuit_dir).unwrap();
+        fs::write(circuit_dir.join("out"), "compiled").unwrap();
+
+        let first = Workspace::new(&circuit_dir).unwrap();
+        let second = Workspace::new(&circuit_dir).unwrap();
+        assert_ne!(first.path(), second.path());
+        assert_eq!(
+            fs::read_to_string(first.path().join("out")).unwrap(),
+            "compiled"
+        );
+        assert!(!first.path().join("abi.json").exists());
+
+        // 작업 파일은 작업 디렉토리마다 따로 쓰입니다.
+        fs::write(first.path().join("witness"), "1").unwrap();
+        fs::write(second.path().join("witness"), "2").unwrap();
+        assert_eq!(
+            fs::read_to_string(first.path().join("witness")).unwrap(),
+            "1"
+        );
+        assert!(!circuit_dir.join("witness").exists());
+
+        let dir = first.path().to_owned();
+        drop(first);
+        assert!(!dir.exists());
+        assert!(circuit_dir.join("out").exists());
+        drop(second);
+        fs::remove_dir_all(&circuit_dir).unwrap();
+        assert!(matches!(
+            Workspace::new(&circuit_dir),
+            Err(CredentialError::MissingSetup(_))
+        ));
+    }
+}